@@ -0,0 +1,142 @@
+use std::rc::Rc;
+
+use rumpose_core::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Brush {
+    Solid(Color),
+    /// Linear gradient between two points given as fractions of the painted
+    /// area.
+    LinearGradient {
+        colors: Rc<[Color]>,
+        stops: Option<Rc<[f32]>>,
+        start: Point2D,
+        end: Point2D,
+    },
+    /// Radial gradient with the center given as a fraction of the painted area
+    /// and the radius as a fraction of its smaller dimension.
+    RadialGradient {
+        colors: Rc<[Color]>,
+        stops: Option<Rc<[f32]>>,
+        center: Point2D,
+        radius: f32,
+    },
+}
+
+impl Brush {
+    #[must_use]
+    pub fn linear_gradient(colors: impl Into<Rc<[Color]>>, start: Point2D, end: Point2D) -> Self {
+        Self::LinearGradient {
+            colors: colors.into(),
+            stops: None,
+            start,
+            end,
+        }
+    }
+
+    #[must_use]
+    pub fn horizontal_gradient(colors: impl Into<Rc<[Color]>>) -> Self {
+        Self::linear_gradient(colors, Point2D::new(0., 0.5), Point2D::new(1., 0.5))
+    }
+
+    #[must_use]
+    pub fn vertical_gradient(colors: impl Into<Rc<[Color]>>) -> Self {
+        Self::linear_gradient(colors, Point2D::new(0.5, 0.), Point2D::new(0.5, 1.))
+    }
+
+    #[must_use]
+    pub fn radial_gradient(colors: impl Into<Rc<[Color]>>) -> Self {
+        Self::RadialGradient {
+            colors: colors.into(),
+            stops: None,
+            center: Point2D::new(0.5, 0.5),
+            radius: 0.5,
+        }
+    }
+
+    /// Sets explicit color stops; must have the same length as the colors.
+    #[must_use]
+    pub fn stops(self, value: impl Into<Rc<[f32]>>) -> Self {
+        match self {
+            Self::Solid(_) => self,
+            Self::LinearGradient {
+                colors, start, end, ..
+            } => Self::LinearGradient {
+                colors,
+                stops: Some(value.into()),
+                start,
+                end,
+            },
+            Self::RadialGradient {
+                colors,
+                center,
+                radius,
+                ..
+            } => Self::RadialGradient {
+                colors,
+                stops: Some(value.into()),
+                center,
+                radius,
+            },
+        }
+    }
+
+    fn engine_colors(colors: &[Color]) -> Vec<EngineColor> {
+        colors.iter().map(|color| color.to_engine()).collect()
+    }
+
+    #[must_use]
+    pub fn to_paint(&self, area: Rect2D) -> Paint {
+        let mut paint = Paint::default();
+
+        paint.set_anti_alias(true);
+
+        match self {
+            Self::Solid(color) => {
+                paint.set_color4f(color.to_engine4f(), None);
+            }
+            Self::LinearGradient {
+                colors,
+                stops,
+                start,
+                end,
+            } => {
+                paint.set_shader(Shader::linear_gradient(
+                    (
+                        area.origin + *start * Point2D::from(area.size),
+                        area.origin + *end * Point2D::from(area.size),
+                    ),
+                    Self::engine_colors(colors).as_slice(),
+                    stops.as_deref(),
+                    TileMode::Clamp,
+                    None,
+                    None,
+                ));
+            }
+            Self::RadialGradient {
+                colors,
+                stops,
+                center,
+                radius,
+            } => {
+                paint.set_shader(Shader::radial_gradient(
+                    area.origin + *center * Point2D::from(area.size),
+                    area.size.width.min(area.size.height) * radius,
+                    Self::engine_colors(colors).as_slice(),
+                    stops.as_deref(),
+                    TileMode::Clamp,
+                    None,
+                    None,
+                ));
+            }
+        }
+
+        paint
+    }
+}
+
+impl From<Color> for Brush {
+    fn from(value: Color) -> Self {
+        Self::Solid(value)
+    }
+}
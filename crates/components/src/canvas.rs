@@ -0,0 +1,335 @@
+use rumpose_core::prelude::*;
+
+use crate::{Brush, ImageBitmap, ModifierElement, PathData, TextProps, modified};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StrokeCap {
+    #[default]
+    Butt,
+    Round,
+    Square,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StrokeJoin {
+    #[default]
+    Miter,
+    Round,
+    Bevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stroke {
+    pub width: f32,
+    pub cap: StrokeCap,
+    pub join: StrokeJoin,
+}
+
+impl Stroke {
+    #[must_use]
+    pub const fn new(width: f32) -> Self {
+        Self {
+            width,
+            cap: StrokeCap::Butt,
+            join: StrokeJoin::Miter,
+        }
+    }
+
+    #[must_use]
+    pub const fn cap(mut self, value: StrokeCap) -> Self {
+        self.cap = value;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn join(mut self, value: StrokeJoin) -> Self {
+        self.join = value;
+
+        self
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum DrawStyle {
+    #[default]
+    Fill,
+    Stroke(Stroke),
+}
+
+impl From<Stroke> for DrawStyle {
+    fn from(value: Stroke) -> Self {
+        Self::Stroke(value)
+    }
+}
+
+/// Transformations applied to everything drawn inside
+/// [`DrawScope::with_transform`].
+pub struct DrawTransform<'a>(&'a Canvas);
+
+impl DrawTransform<'_> {
+    pub fn translate(&self, x: f32, y: f32) {
+        self.0.translate((x, y));
+    }
+
+    /// Rotates clockwise by `degrees` around `pivot`.
+    pub fn rotate(&self, degrees: f32, pivot: Point2D) {
+        self.0.rotate(degrees, Some(Point::from(pivot)));
+    }
+
+    pub fn scale(&self, horizontal: f32, vertical: f32, pivot: Point2D) {
+        self.0.translate(Point::from(pivot));
+        self.0.scale((horizontal, vertical));
+        self.0.translate(Point::from(-pivot));
+    }
+}
+
+/// Drawing surface of a [`canvas`], with coordinates relative to the top left
+/// corner of the node.
+pub struct DrawScope<'a> {
+    canvas: &'a Canvas,
+    font_manager: &'a FontCollection,
+    density: Density,
+    size: Size2D,
+}
+
+impl DrawScope<'_> {
+    fn paint(brush: impl Into<Brush>, bounds: Rect2D, style: impl Into<DrawStyle>) -> Paint {
+        let mut paint = brush.into().to_paint(bounds);
+
+        if let DrawStyle::Stroke(stroke) = style.into() {
+            paint.set_stroke(true);
+            paint.set_stroke_width(stroke.width);
+            paint.set_stroke_cap(match stroke.cap {
+                StrokeCap::Butt => PaintCap::Butt,
+                StrokeCap::Round => PaintCap::Round,
+                StrokeCap::Square => PaintCap::Square,
+            });
+            paint.set_stroke_join(match stroke.join {
+                StrokeJoin::Miter => PaintJoin::Miter,
+                StrokeJoin::Round => PaintJoin::Round,
+                StrokeJoin::Bevel => PaintJoin::Bevel,
+            });
+        }
+
+        paint
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> Size2D {
+        self.size
+    }
+
+    /// Density of the output, for converting [`Dp`] and [`Sp`] to the pixels
+    /// the scope draws in.
+    #[must_use]
+    pub const fn density(&self) -> Density {
+        self.density
+    }
+
+    #[must_use]
+    pub fn center(&self) -> Point2D {
+        self.size.center()
+    }
+
+    pub fn draw_line(&self, brush: impl Into<Brush>, start: Point2D, end: Point2D, stroke: Stroke) {
+        let bounds = Rect2D::from_xywh(
+            start.x.min(end.x),
+            start.y.min(end.y),
+            (end.x - start.x).abs(),
+            (end.y - start.y).abs(),
+        );
+
+        self.canvas.draw_line(
+            Point::from(start),
+            Point::from(end),
+            &Self::paint(brush, bounds, stroke),
+        );
+    }
+
+    pub fn draw_rect(
+        &self,
+        brush: impl Into<Brush>,
+        top_left: Point2D,
+        size: Size2D,
+        style: impl Into<DrawStyle>,
+    ) {
+        let bounds = Rect2D::new(top_left, size);
+
+        self.canvas
+            .draw_rect(Rect::from(bounds), &Self::paint(brush, bounds, style));
+    }
+
+    pub fn draw_round_rect(
+        &self,
+        brush: impl Into<Brush>,
+        top_left: Point2D,
+        size: Size2D,
+        corner_radius: f32,
+        style: impl Into<DrawStyle>,
+    ) {
+        let bounds = Rect2D::new(top_left, size);
+
+        self.canvas.draw_round_rect(
+            Rect::from(bounds),
+            corner_radius,
+            corner_radius,
+            &Self::paint(brush, bounds, style),
+        );
+    }
+
+    pub fn draw_circle(
+        &self,
+        brush: impl Into<Brush>,
+        radius: f32,
+        center: Point2D,
+        style: impl Into<DrawStyle>,
+    ) {
+        let bounds = Rect2D::new(center - radius, Size2D::new(radius * 2., radius * 2.));
+
+        self.canvas.draw_circle(
+            Point::from(center),
+            radius,
+            &Self::paint(brush, bounds, style),
+        );
+    }
+
+    /// Draws an arc of the oval inscribed into the given bounds, with angles
+    /// in degrees clockwise from the positive x axis.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_arc(
+        &self,
+        brush: impl Into<Brush>,
+        start_angle: f32,
+        sweep_angle: f32,
+        use_center: bool,
+        top_left: Point2D,
+        size: Size2D,
+        style: impl Into<DrawStyle>,
+    ) {
+        let bounds = Rect2D::new(top_left, size);
+
+        self.canvas.draw_arc(
+            Rect::from(bounds),
+            start_angle,
+            sweep_angle,
+            use_center,
+            &Self::paint(brush, bounds, style),
+        );
+    }
+
+    pub fn draw_path(&self, path: &PathData, brush: impl Into<Brush>, style: impl Into<DrawStyle>) {
+        self.canvas
+            .draw_path(&path.0, &Self::paint(brush, path.bounds(), style));
+    }
+
+    pub fn draw_image(&self, image: &ImageBitmap, top_left: Point2D) {
+        self.canvas
+            .draw_image(&image.0, Point::from(top_left), None);
+    }
+
+    pub fn draw_image_rect(&self, image: &ImageBitmap, dst: Rect2D) {
+        let mut paint = Paint::default();
+
+        paint.set_anti_alias(true);
+
+        self.canvas
+            .draw_image_rect(&image.0, None, Rect::from(dst), &paint);
+    }
+
+    /// Draws `text` with its top left corner at `top_left`, wrapped to the
+    /// width left in the scope, in the default family of the registry like
+    /// [`text`](crate::text).
+    pub fn draw_text(&self, text: &str, top_left: Point2D, color: Color, font_size: impl Into<Sp>) {
+        let result = TextProps::new(text)
+            .color(color)
+            .font_size(font_size)
+            .layout(
+                self.font_manager,
+                self.density,
+                Constraints::new(
+                    0.,
+                    (self.size.width - top_left.x).max(0.),
+                    0.,
+                    f32::INFINITY,
+                ),
+            );
+
+        result.paint(self.canvas, top_left);
+    }
+
+    /// Draws `block` with the transformations set up by `transform`.
+    pub fn with_transform(
+        &self,
+        transform: impl FnOnce(&DrawTransform),
+        block: impl FnOnce(&Self),
+    ) {
+        self.canvas.save();
+
+        transform(&DrawTransform(self.canvas));
+        block(self);
+
+        self.canvas.restore();
+    }
+
+    pub fn clip_rect(&self, rect: Rect2D, block: impl FnOnce(&Self)) {
+        self.canvas.save();
+        self.canvas.clip_rect(Rect::from(rect), None, Some(true));
+
+        block(self);
+
+        self.canvas.restore();
+    }
+
+    /// Draws `block` in a scope shrunk by the given insets, with its origin
+    /// moved accordingly.
+    pub fn inset(&self, left: f32, top: f32, right: f32, bottom: f32, block: impl FnOnce(&Self)) {
+        self.canvas.save();
+        self.canvas.translate((left, top));
+
+        block(&DrawScope {
+            canvas: self.canvas,
+            font_manager: self.font_manager,
+            density: self.density,
+            size: Size2D::new(
+                (self.size.width - left - right).max(0.),
+                (self.size.height - top - bottom).max(0.),
+            ),
+        });
+
+        self.canvas.restore();
+    }
+}
+
+#[track_caller]
+pub fn canvas<D: Fn(&DrawScope) + Clone + 'static>(
+    scope: Scope,
+    modifier: impl ModifierElement,
+    on_draw: D,
+) {
+    modified(scope, &modifier, move |scope| {
+        let on_draw = on_draw.clone();
+
+        draw(
+            scope,
+            move |context| {
+                let area = context.area();
+
+                context.with_canvas(|canvas| {
+                    canvas.save();
+                    canvas.translate(Point::from(area.origin));
+
+                    on_draw(&DrawScope {
+                        canvas,
+                        font_manager: context.font_manager(),
+                        density: context.density(),
+                        size: area.size,
+                    });
+
+                    canvas.restore();
+                });
+            },
+            |scope| layout(scope, |_, _, constraints| constraints.min, |_| {}),
+        );
+    });
+}
@@ -0,0 +1,29 @@
+use std::{cell::RefCell, fmt::Debug};
+
+/// Access to the system clipboard, provided by the host.
+pub trait Clipboard: Debug {
+    fn text(&self) -> Option<String>;
+    fn set_text(&self, text: &str);
+}
+
+/// Clipboard kept in memory, for hosts without a system clipboard and for
+/// tests.
+#[derive(Debug, Default)]
+pub struct MemoryClipboard(RefCell<Option<String>>);
+
+impl MemoryClipboard {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Clipboard for MemoryClipboard {
+    fn text(&self) -> Option<String> {
+        self.0.borrow().clone()
+    }
+
+    fn set_text(&self, text: &str) {
+        self.0.replace(Some(text.to_owned()));
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    rc::Rc,
+};
+
+use rumpose_core::prelude::*;
+
+use super::{TOUCH_SLOP, VelocityTracker, distance};
+
+/// Axis a drag is restricted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+impl Orientation {
+    /// Returns the component of `offset` along the axis.
+    #[must_use]
+    pub const fn constrain(self, offset: Point2D) -> Point2D {
+        match self {
+            Self::Horizontal => Point2D::new(offset.x, 0.),
+            Self::Vertical => Point2D::new(0., offset.y),
+        }
+    }
+}
+
+/// Callbacks of
+/// [`detect_drag_gestures`](super::PointerInputScope::detect_drag_gestures).
+#[derive(Default, Clone)]
+pub struct DragGestures {
+    orientation: Option<Orientation>,
+    on_drag_start: Option<Rc<dyn Fn(Point2D)>>,
+    on_drag: Option<Rc<dyn Fn(Point2D, Point2D)>>,
+    on_drag_end: Option<Rc<dyn Fn(Point2D)>>,
+    on_drag_cancel: Option<Rc<dyn Fn()>>,
+}
+
+impl DragGestures {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only detects drags along the axis, leaving the others to the parents,
+    /// and reports deltas and velocities along it.
+    #[must_use]
+    pub const fn orientation(mut self, value: Orientation) -> Self {
+        self.orientation = Some(value);
+
+        self
+    }
+
+    /// Called with the position of the press once the pointer moved past the
+    /// touch slop.
+    #[must_use]
+    pub fn on_drag_start<F: Fn(Point2D) + 'static>(mut self, callback: F) -> Self {
+        self.on_drag_start = Some(Rc::new(callback));
+
+        self
+    }
+
+    /// Called with the position of the pointer and the distance it moved
+    /// since the previous call, or since the press for the first call.
+    #[must_use]
+    pub fn on_drag<F: Fn(Point2D, Point2D) + 'static>(mut self, callback: F) -> Self {
+        self.on_drag = Some(Rc::new(callback));
+
+        self
+    }
+
+    /// Called with the velocity of the pointer in pixels per second when it
+    /// is released, e.g. to fling.
+    #[must_use]
+    pub fn on_drag_end<F: Fn(Point2D) + 'static>(mut self, callback: F) -> Self {
+        self.on_drag_end = Some(Rc::new(callback));
+
+        self
+    }
+
+    /// Called when the pointer is canceled or another detector consumes its
+    /// moves.
+    #[must_use]
+    pub fn on_drag_cancel<F: Fn() + 'static>(mut self, callback: F) -> Self {
+        self.on_drag_cancel = Some(Rc::new(callback));
+
+        self
+    }
+
+    fn constrain(&self, offset: Point2D) -> Point2D {
+        self.orientation
+            .map_or(offset, |orientation| orientation.constrain(offset))
+    }
+
+    fn cancel(&self) {
+        if let Some(on_drag_cancel) = &self.on_drag_cancel {
+            on_drag_cancel();
+        }
+    }
+}
+
+impl fmt::Debug for DragGestures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DragGestures")
+            .field("orientation", &self.orientation)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DragPointer {
+    id: PointerId,
+    start: Point2D,
+    last: Point2D,
+    dragging: bool,
+}
+
+pub(super) fn detector(gestures: DragGestures) -> impl Fn(&PointerEvent, PointerEventPass) {
+    let state = Cell::new(None::<DragPointer>);
+    let velocity = RefCell::new(VelocityTracker::new());
+
+    move |event, pass| {
+        // A parent took over the pointer before the drag started, e.g. a
+        // scroll container along the other axis.
+        if pass == PointerEventPass::Final
+            && event.kind == PointerEventType::Move
+            && event.is_consumed()
+            && state
+                .get()
+                .is_some_and(|drag| drag.id == event.id && !drag.dragging)
+        {
+            state.set(None);
+        }
+
+        if pass != PointerEventPass::Main {
+            return;
+        }
+
+        let Some(mut drag) = state.get() else {
+            // Children may have consumed the press, e.g. a clickable in a
+            // scrolling list. They give up the pointer once the drag starts.
+            if event.kind == PointerEventType::Press && event.button == PointerButton::Primary {
+                let mut velocity = velocity.borrow_mut();
+
+                velocity.reset();
+                velocity.add_position(event.time, event.position);
+
+                state.set(Some(DragPointer {
+                    id: event.id,
+                    start: event.position,
+                    last: event.position,
+                    dragging: false,
+                }));
+            }
+
+            return;
+        };
+
+        if drag.id != event.id {
+            return;
+        }
+
+        match event.kind {
+            PointerEventType::Move if event.is_consumed() => {
+                if drag.dragging {
+                    gestures.cancel();
+                }
+
+                state.set(None);
+            }
+            PointerEventType::Move => {
+                velocity
+                    .borrow_mut()
+                    .add_position(event.time, event.position);
+
+                if !drag.dragging {
+                    let slop = TOUCH_SLOP.to_px(event.density);
+
+                    if distance(gestures.constrain(event.position - drag.start)) <= slop {
+                        return;
+                    }
+
+                    drag.dragging = true;
+                    drag.last = drag.start;
+
+                    if let Some(on_drag_start) = &gestures.on_drag_start {
+                        on_drag_start(drag.start);
+                    }
+                }
+
+                event.consume();
+
+                if let Some(on_drag) = &gestures.on_drag {
+                    on_drag(
+                        event.position,
+                        gestures.constrain(event.position - drag.last),
+                    );
+                }
+
+                drag.last = event.position;
+                state.set(Some(drag));
+            }
+            PointerEventType::Release => {
+                if drag.dragging {
+                    event.consume();
+
+                    if let Some(on_drag_end) = &gestures.on_drag_end {
+                        on_drag_end(gestures.constrain(velocity.borrow().velocity()));
+                    }
+                }
+
+                state.set(None);
+            }
+            PointerEventType::Cancel => {
+                if drag.dragging {
+                    gestures.cancel();
+                }
+
+                state.set(None);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        PointerInputScope,
+        gesture::testing::{self, dispatch},
+    };
+
+    #[derive(Debug, PartialEq)]
+    enum Call {
+        Start(Point2D),
+        Drag(Point2D),
+        End(Point2D),
+        Cancel,
+    }
+
+    fn scope(gestures: DragGestures) -> (PointerInputScope, Rc<RefCell<Vec<Call>>>) {
+        testing::scope(|scope, calls| {
+            let (start, drag, end, cancel) =
+                (calls.clone(), calls.clone(), calls.clone(), calls.clone());
+
+            scope.detect_drag_gestures(
+                gestures
+                    .on_drag_start(move |position| start.borrow_mut().push(Call::Start(position)))
+                    .on_drag(move |_, delta| drag.borrow_mut().push(Call::Drag(delta)))
+                    .on_drag_end(move |velocity| end.borrow_mut().push(Call::End(velocity)))
+                    .on_drag_cancel(move || cancel.borrow_mut().push(Call::Cancel)),
+            );
+        })
+    }
+
+    #[test]
+    fn drag_past_slop() {
+        let (scope, calls) = scope(DragGestures::new());
+
+        dispatch(&scope, PointerEvent::press(Point2D::new(10., 10.)), 0);
+
+        let within_slop = dispatch(&scope, PointerEvent::moved(Point2D::new(14., 10.)), 10);
+
+        assert!(!within_slop.is_consumed());
+        assert!(calls.borrow().is_empty());
+
+        let dragged = dispatch(&scope, PointerEvent::moved(Point2D::new(30., 10.)), 20);
+
+        assert!(dragged.is_consumed());
+
+        dispatch(&scope, PointerEvent::moved(Point2D::new(40., 10.)), 30);
+        dispatch(&scope, PointerEvent::release(Point2D::new(40., 10.)), 30);
+
+        let calls = calls.borrow();
+
+        assert_eq!(calls[..3], [
+            Call::Start(Point2D::new(10., 10.)),
+            Call::Drag(Point2D::new(20., 0.)),
+            Call::Drag(Point2D::new(10., 0.)),
+        ]);
+        assert!(matches!(calls[3], Call::End(velocity) if velocity.x > 0. && velocity.y == 0.));
+    }
+
+    #[test]
+    fn orientation_ignores_other_axis() {
+        let (scope, calls) = scope(DragGestures::new().orientation(Orientation::Horizontal));
+
+        dispatch(&scope, PointerEvent::press(Point2D::new(10., 10.)), 0);
+
+        let moved = dispatch(&scope, PointerEvent::moved(Point2D::new(12., 60.)), 10);
+
+        assert!(!moved.is_consumed());
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[test]
+    fn consumed_move_cancels() {
+        let (scope, calls) = scope(DragGestures::new());
+
+        dispatch(&scope, PointerEvent::press(Point2D::new(10., 10.)), 0);
+        dispatch(&scope, PointerEvent::moved(Point2D::new(30., 10.)), 10);
+
+        let event = PointerEvent::moved(Point2D::new(40., 10.));
+
+        event.consume();
+        dispatch(&scope, event, 20);
+
+        assert_eq!(calls.borrow().last(), Some(&Call::Cancel));
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt;
+
+use rumpose_core::prelude::*;
+
+mod drag;
+mod tap;
+mod transform;
+mod velocity;
+
+pub(crate) use self::tap::TapDetector;
+pub use self::{
+    drag::{DragGestures, Orientation},
+    tap::TapGestures,
+    velocity::VelocityTracker,
+};
+
+/// Distance a pointer has to move before it starts a drag or a transform,
+/// so that taps with a shaky finger stay taps.
+const TOUCH_SLOP: Dp = Dp(8.);
+
+type Detector = Box<dyn Fn(&PointerEvent, PointerEventPass)>;
+
+/// Gesture detectors of a
+/// [`pointer_input`](crate::InputModifierExt::pointer_input) modifier. Every
+/// event reaching the modifier goes through all of them in the order they
+/// were added, each keeping its own state between events.
+#[derive(Default)]
+pub struct PointerInputScope {
+    detectors: Vec<Detector>,
+}
+
+impl PointerInputScope {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Passes every event to `handler`, for gestures not covered by the
+    /// detectors.
+    pub fn on_pointer_event<F: Fn(&PointerEvent, PointerEventPass) + 'static>(
+        &mut self,
+        handler: F,
+    ) {
+        self.detectors.push(Box::new(handler));
+    }
+
+    pub fn detect_tap_gestures(&mut self, gestures: TapGestures) {
+        self.on_pointer_event(tap::detector(gestures));
+    }
+
+    pub fn detect_drag_gestures(&mut self, gestures: DragGestures) {
+        self.on_pointer_event(drag::detector(gestures));
+    }
+
+    /// Calls `on_gesture` with the centroid of the pressed pointers, and the
+    /// pan, zoom factor and rotation in degrees since the previous call,
+    /// once the pointers moved past the touch slop.
+    pub fn detect_transform_gestures<F: Fn(Point2D, Point2D, f32, f32) + 'static>(
+        &mut self,
+        on_gesture: F,
+    ) {
+        self.on_pointer_event(transform::detector(on_gesture));
+    }
+
+    /// Passes an event to the detectors. Called by the modifier, or with a
+    /// synthetic sequence of events to check the detected gestures.
+    pub fn dispatch(&self, event: &PointerEvent, pass: PointerEventPass) {
+        for detector in &self.detectors {
+            detector(event, pass);
+        }
+    }
+}
+
+impl fmt::Debug for PointerInputScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PointerInputScope")
+            .field("detectors", &self.detectors.len())
+            .finish()
+    }
+}
+
+fn distance(point: Point2D) -> f32 {
+    point.x.hypot(point.y)
+}
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use std::{cell::RefCell, rc::Rc, time::Duration};
+
+    use rumpose_core::prelude::*;
+
+    use super::PointerInputScope;
+
+    /// Returns a scope with the detectors added by `detect`, which records
+    /// the callbacks it is interested in to the returned calls.
+    pub(crate) fn scope<T>(
+        detect: impl FnOnce(&mut PointerInputScope, &Rc<RefCell<Vec<T>>>),
+    ) -> (PointerInputScope, Rc<RefCell<Vec<T>>>) {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let mut scope = PointerInputScope::new();
+
+        detect(&mut scope, &calls);
+
+        (scope, calls)
+    }
+
+    /// Dispatches an event at `millis` to a node of 100 by 100 pixels, in
+    /// every pass.
+    pub(crate) fn dispatch(
+        scope: &PointerInputScope,
+        event: PointerEvent,
+        millis: u64,
+    ) -> PointerEvent {
+        let event = event
+            .with_size(Size2D::new(100., 100.))
+            .with_time(Duration::from_millis(millis));
+
+        for pass in [
+            PointerEventPass::Initial,
+            PointerEventPass::Main,
+            PointerEventPass::Final,
+        ] {
+            scope.dispatch(&event, pass);
+        }
+
+        event
+    }
+}
@@ -0,0 +1,342 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    rc::Rc,
+    time::Duration,
+};
+
+use rumpose_core::prelude::*;
+
+/// Time a press has to last to be a long press.
+const LONG_PRESS_TIMEOUT: Duration = Duration::from_millis(500);
+/// Maximum time between two taps of a double tap.
+const DOUBLE_TAP_TIMEOUT: Duration = Duration::from_millis(300);
+
+type TapCallback = Option<Rc<dyn Fn(Point2D)>>;
+type EndCallback = Option<Rc<dyn Fn()>>;
+
+/// Callbacks of
+/// [`detect_tap_gestures`](super::PointerInputScope::detect_tap_gestures),
+/// called with the position of the pointer.
+#[derive(Default, Clone)]
+pub struct TapGestures {
+    on_press: TapCallback,
+    on_tap: TapCallback,
+    on_double_tap: TapCallback,
+    on_long_press: TapCallback,
+    on_release: EndCallback,
+    on_cancel: EndCallback,
+}
+
+impl TapGestures {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn on_press<F: Fn(Point2D) + 'static>(mut self, callback: F) -> Self {
+        self.on_press = Some(Rc::new(callback));
+
+        self
+    }
+
+    #[must_use]
+    pub fn on_tap<F: Fn(Point2D) + 'static>(mut self, callback: F) -> Self {
+        self.on_tap = Some(Rc::new(callback));
+
+        self
+    }
+
+    /// Called instead of the tap for a tap following the previous one quickly
+    /// enough. The first tap is still reported.
+    #[must_use]
+    pub fn on_double_tap<F: Fn(Point2D) + 'static>(mut self, callback: F) -> Self {
+        self.on_double_tap = Some(Rc::new(callback));
+
+        self
+    }
+
+    /// Called instead of the tap when the press lasts long enough, on the
+    /// first frame or pointer event past the timeout.
+    #[must_use]
+    pub fn on_long_press<F: Fn(Point2D) + 'static>(mut self, callback: F) -> Self {
+        self.on_long_press = Some(Rc::new(callback));
+
+        self
+    }
+
+    /// Called when the pressed pointer is released, before the tap.
+    #[must_use]
+    pub fn on_release<F: Fn() + 'static>(mut self, callback: F) -> Self {
+        self.on_release = Some(Rc::new(callback));
+
+        self
+    }
+
+    /// Called when the press ends without a release, e.g. when the pointer
+    /// leaves or a parent takes it over.
+    #[must_use]
+    pub fn on_cancel<F: Fn() + 'static>(mut self, callback: F) -> Self {
+        self.on_cancel = Some(Rc::new(callback));
+
+        self
+    }
+}
+
+impl fmt::Debug for TapGestures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TapGestures")
+            .field("on_press", &self.on_press.is_some())
+            .field("on_tap", &self.on_tap.is_some())
+            .field("on_double_tap", &self.on_double_tap.is_some())
+            .field("on_long_press", &self.on_long_press.is_some())
+            .field("on_release", &self.on_release.is_some())
+            .field("on_cancel", &self.on_cancel.is_some())
+            .finish()
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct TapState {
+    press: Option<(PointerId, Duration, Point2D)>,
+    /// Number of the current press, so frames scheduled for a previous one
+    /// stop.
+    presses: u64,
+    long_pressed: bool,
+    last_tap: Option<Duration>,
+}
+
+/// State of a tap detection, shared with the frames waiting for a long press.
+/// Kept across recompositions by [`clickable`](crate::ClickableModifierExt),
+/// which replaces the gestures every time.
+pub(crate) struct TapDetector {
+    state: Cell<TapState>,
+    gestures: RefCell<TapGestures>,
+}
+
+impl TapDetector {
+    pub(crate) fn new(gestures: TapGestures) -> Rc<Self> {
+        Rc::new(Self {
+            state: Cell::default(),
+            gestures: RefCell::new(gestures),
+        })
+    }
+
+    pub(crate) fn set_gestures(&self, gestures: TapGestures) {
+        self.gestures.replace(gestures);
+    }
+
+    fn cancel(&self, tap: &mut TapState) {
+        tap.press = None;
+
+        let on_cancel = self.gestures.borrow().on_cancel.clone();
+
+        if let Some(on_cancel) = on_cancel {
+            on_cancel();
+        }
+    }
+
+    fn long_press(&self, tap: &mut TapState, position: Point2D) {
+        let on_long_press = self.gestures.borrow().on_long_press.clone();
+
+        if let Some(on_long_press) = on_long_press.filter(|_| !tap.long_pressed) {
+            tap.long_pressed = true;
+
+            on_long_press(position);
+        }
+    }
+
+    /// Checks for a long press on every frame while the press lasts, so it is
+    /// detected without further pointer events. Frame times are measured from
+    /// the first frame, as they may not share the clock of the events.
+    fn schedule_long_press(self: &Rc<Self>, presses: u64, start: Option<Duration>) {
+        let detector = self.clone();
+
+        request_frame(move |time| {
+            let mut tap = detector.state.get();
+
+            if tap.presses != presses || tap.long_pressed {
+                return;
+            }
+
+            let Some((_, _, position)) = tap.press else {
+                return;
+            };
+
+            let start = start.unwrap_or(time);
+
+            if time.saturating_sub(start) >= LONG_PRESS_TIMEOUT {
+                detector.long_press(&mut tap, position);
+                detector.state.set(tap);
+            } else {
+                detector.schedule_long_press(presses, Some(start));
+            }
+        });
+    }
+
+    pub(crate) fn on_event(self: &Rc<Self>, event: &PointerEvent, pass: PointerEventPass) {
+        let mut tap = self.state.get();
+        let pressed_at = tap
+            .press
+            .filter(|(id, ..)| *id == event.id)
+            .map(|(_, time, _)| time);
+
+        match (pass, event.kind, pressed_at) {
+            (PointerEventPass::Main, PointerEventType::Press, _)
+                if tap.press.is_none()
+                    && !event.is_consumed()
+                    && event.button == PointerButton::Primary =>
+            {
+                tap.press = Some((event.id, event.time, event.position));
+                tap.presses += 1;
+                tap.long_pressed = false;
+
+                event.consume();
+
+                let gestures = self.gestures.borrow().clone();
+
+                if gestures.on_long_press.is_some() {
+                    self.schedule_long_press(tap.presses, None);
+                }
+
+                if let Some(on_press) = &gestures.on_press {
+                    on_press(event.position);
+                }
+            }
+            (PointerEventPass::Main, PointerEventType::Cancel, Some(_)) => {
+                self.cancel(&mut tap);
+            }
+            (
+                PointerEventPass::Main,
+                PointerEventType::Move | PointerEventType::Release,
+                Some(_),
+            ) if event.is_consumed() || !event.is_inside() => {
+                self.cancel(&mut tap);
+            }
+            (PointerEventPass::Main, kind, Some(pressed_at)) => {
+                if event.time.saturating_sub(pressed_at) >= LONG_PRESS_TIMEOUT {
+                    self.long_press(&mut tap, event.position);
+                }
+
+                if kind == PointerEventType::Release {
+                    let gestures = self.gestures.borrow().clone();
+
+                    tap.press = None;
+
+                    event.consume();
+
+                    if let Some(on_release) = &gestures.on_release {
+                        on_release();
+                    }
+
+                    if !tap.long_pressed {
+                        let double_tap = gestures.on_double_tap.as_ref().filter(|_| {
+                            tap.last_tap.is_some_and(|last| {
+                                event.time.saturating_sub(last) <= DOUBLE_TAP_TIMEOUT
+                            })
+                        });
+
+                        if let Some(on_double_tap) = double_tap {
+                            tap.last_tap = None;
+
+                            on_double_tap(event.position);
+                        } else {
+                            tap.last_tap = Some(event.time);
+
+                            if let Some(on_tap) = &gestures.on_tap {
+                                on_tap(event.position);
+                            }
+                        }
+                    }
+                }
+            }
+            // A parent, e.g. a drag detector, took over the pointer.
+            (PointerEventPass::Final, PointerEventType::Move, Some(_)) if event.is_consumed() => {
+                self.cancel(&mut tap);
+            }
+            _ => {}
+        }
+
+        self.state.set(tap);
+    }
+}
+
+pub(super) fn detector(gestures: TapGestures) -> impl Fn(&PointerEvent, PointerEventPass) {
+    let detector = TapDetector::new(gestures);
+
+    move |event, pass| detector.on_event(event, pass)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        PointerInputScope,
+        gesture::testing::{self, dispatch},
+    };
+
+    /// Records the callbacks called by the tap detector of a scope.
+    fn scope() -> (PointerInputScope, Rc<RefCell<Vec<&'static str>>>) {
+        testing::scope(|scope, calls| {
+            let record = |name: &'static str| {
+                let calls = calls.clone();
+
+                move |_: Point2D| calls.borrow_mut().push(name)
+            };
+            let cancel = calls.clone();
+
+            scope.detect_tap_gestures(
+                TapGestures::new()
+                    .on_press(record("press"))
+                    .on_tap(record("tap"))
+                    .on_double_tap(record("double tap"))
+                    .on_long_press(record("long press"))
+                    .on_cancel(move || cancel.borrow_mut().push("cancel")),
+            );
+        })
+    }
+
+    #[test]
+    fn tap() {
+        let (scope, calls) = scope();
+
+        dispatch(&scope, PointerEvent::press(Point2D::new(10., 10.)), 0);
+        dispatch(&scope, PointerEvent::release(Point2D::new(12., 10.)), 50);
+
+        assert_eq!(*calls.borrow(), ["press", "tap"]);
+    }
+
+    #[test]
+    fn release_outside_cancels() {
+        let (scope, calls) = scope();
+
+        dispatch(&scope, PointerEvent::press(Point2D::new(10., 10.)), 0);
+        dispatch(&scope, PointerEvent::release(Point2D::new(150., 10.)), 50);
+
+        assert_eq!(*calls.borrow(), ["press", "cancel"]);
+    }
+
+    #[test]
+    fn double_tap() {
+        let (scope, calls) = scope();
+
+        dispatch(&scope, PointerEvent::press(Point2D::new(10., 10.)), 0);
+        dispatch(&scope, PointerEvent::release(Point2D::new(10., 10.)), 50);
+        dispatch(&scope, PointerEvent::press(Point2D::new(10., 10.)), 150);
+        dispatch(&scope, PointerEvent::release(Point2D::new(10., 10.)), 200);
+
+        assert_eq!(*calls.borrow(), ["press", "tap", "press", "double tap"]);
+    }
+
+    #[test]
+    fn long_press_replaces_tap() {
+        let (scope, calls) = scope();
+
+        dispatch(&scope, PointerEvent::press(Point2D::new(10., 10.)), 0);
+        dispatch(&scope, PointerEvent::moved(Point2D::new(11., 10.)), 600);
+        dispatch(&scope, PointerEvent::release(Point2D::new(11., 10.)), 700);
+
+        assert_eq!(*calls.borrow(), ["press", "long press"]);
+    }
+}
@@ -0,0 +1,288 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+use rumpose_core::prelude::*;
+
+use super::{TOUCH_SLOP, distance};
+
+#[derive(Debug, Clone, Copy)]
+struct Transform {
+    centroid: Point2D,
+    pan: Point2D,
+    zoom: f32,
+    rotation: f32,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            centroid: Point2D::default(),
+            pan: Point2D::default(),
+            zoom: 1.,
+            rotation: 0.,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct TransformState {
+    pointers: BTreeMap<PointerId, Point2D>,
+    transforming: bool,
+    /// Motion accumulated before reaching the touch slop.
+    pending: Transform,
+}
+
+fn centroid(pointers: &BTreeMap<PointerId, Point2D>) -> Point2D {
+    pointers
+        .values()
+        .fold(Point2D::default(), |sum, &point| sum + point)
+        / pointers.len() as f32
+}
+
+/// Returns the average distance of the pointers to their centroid.
+fn spread(pointers: &BTreeMap<PointerId, Point2D>, centroid: Point2D) -> f32 {
+    pointers
+        .values()
+        .map(|&point| distance(point - centroid))
+        .sum::<f32>()
+        / pointers.len() as f32
+}
+
+/// Returns the change from `previous` to `current` for a single moved
+/// pointer.
+fn transform(
+    previous: &BTreeMap<PointerId, Point2D>,
+    current: &BTreeMap<PointerId, Point2D>,
+) -> Transform {
+    let previous_centroid = centroid(previous);
+    let current_centroid = centroid(current);
+
+    if current.len() < 2 {
+        return Transform {
+            centroid: current_centroid,
+            pan: current_centroid - previous_centroid,
+            ..Transform::default()
+        };
+    }
+
+    let previous_spread = spread(previous, previous_centroid);
+    let current_spread = spread(current, current_centroid);
+    let rotation = previous
+        .iter()
+        .zip(current.values())
+        .map(|((_, &before), &after)| {
+            let before = before - previous_centroid;
+            let after = after - current_centroid;
+            let angle = after.y.atan2(after.x) - before.y.atan2(before.x);
+
+            // Takes the shorter way around.
+            (angle + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI
+        })
+        .sum::<f32>()
+        / current.len() as f32;
+
+    Transform {
+        centroid: current_centroid,
+        pan: current_centroid - previous_centroid,
+        zoom: if previous_spread > 0. {
+            current_spread / previous_spread
+        } else {
+            1.
+        },
+        rotation: rotation.to_degrees(),
+    }
+}
+
+pub(super) fn detector<F: Fn(Point2D, Point2D, f32, f32)>(
+    on_gesture: F,
+) -> impl Fn(&PointerEvent, PointerEventPass) {
+    let state = RefCell::new(TransformState::default());
+
+    move |event, pass| {
+        if pass != PointerEventPass::Main {
+            return;
+        }
+
+        let mut state = state.borrow_mut();
+
+        match event.kind {
+            PointerEventType::Press => {
+                state.pointers.insert(event.id, event.position);
+            }
+            PointerEventType::Move if state.pointers.contains_key(&event.id) => {
+                let previous = state.pointers.clone();
+
+                state.pointers.insert(event.id, event.position);
+
+                if event.is_consumed() {
+                    return;
+                }
+
+                let mut change = transform(&previous, &state.pointers);
+
+                if !state.transforming {
+                    let pending = Transform {
+                        centroid: change.centroid,
+                        pan: state.pending.pan + change.pan,
+                        zoom: state.pending.zoom * change.zoom,
+                        rotation: state.pending.rotation + change.rotation,
+                    };
+                    let size = spread(&state.pointers, change.centroid);
+                    let slop = TOUCH_SLOP.to_px(event.density);
+
+                    state.pending = pending;
+
+                    if distance(pending.pan) <= slop
+                        && (1. - pending.zoom).abs() * size <= slop
+                        && pending.rotation.to_radians().abs() * size <= slop
+                    {
+                        return;
+                    }
+
+                    state.transforming = true;
+                    change = pending;
+                }
+
+                event.consume();
+                drop(state);
+
+                on_gesture(change.centroid, change.pan, change.zoom, change.rotation);
+            }
+            PointerEventType::Release | PointerEventType::Cancel => {
+                state.pointers.remove(&event.id);
+
+                if state.pointers.is_empty() {
+                    state.transforming = false;
+                    state.pending = Transform::default();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::{
+        PointerInputScope,
+        gesture::testing::{self, dispatch},
+    };
+
+    type Call = (Point2D, Point2D, f32, f32);
+
+    fn scope() -> (PointerInputScope, Rc<RefCell<Vec<Call>>>) {
+        testing::scope(|scope, calls| {
+            let calls = calls.clone();
+
+            scope.detect_transform_gestures(move |centroid, pan, zoom, rotation| {
+                calls.borrow_mut().push((centroid, pan, zoom, rotation));
+            });
+        })
+    }
+
+    /// Presses two pointers, with ids 0 and 1.
+    fn press(scope: &PointerInputScope, first: Point2D, second: Point2D) {
+        dispatch(scope, PointerEvent::press(first).with_id(0), 0);
+        dispatch(scope, PointerEvent::press(second).with_id(1), 0);
+    }
+
+    fn move_to(scope: &PointerInputScope, id: PointerId, position: Point2D) {
+        dispatch(scope, PointerEvent::moved(position).with_id(id), 10);
+    }
+
+    fn assert_call(actual: Call, expected: Call) {
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-3;
+
+        assert!(
+            close(actual.0.x, expected.0.x)
+                && close(actual.0.y, expected.0.y)
+                && close(actual.1.x, expected.1.x)
+                && close(actual.1.y, expected.1.y)
+                && close(actual.2, expected.2)
+                && close(actual.3, expected.3),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn pan() {
+        let (scope, calls) = scope();
+
+        press(&scope, Point2D::new(40., 40.), Point2D::new(60., 60.));
+        move_to(&scope, 0, Point2D::new(40., 50.));
+
+        assert!(calls.borrow().is_empty());
+
+        move_to(&scope, 1, Point2D::new(60., 70.));
+
+        assert_eq!(calls.borrow().len(), 1);
+        assert_call(
+            calls.borrow()[0],
+            (Point2D::new(50., 60.), Point2D::new(0., 10.), 1., 0.),
+        );
+    }
+
+    #[test]
+    fn zoom() {
+        let (scope, calls) = scope();
+
+        press(&scope, Point2D::new(40., 50.), Point2D::new(60., 50.));
+        move_to(&scope, 1, Point2D::new(80., 50.));
+        move_to(&scope, 0, Point2D::new(20., 50.));
+
+        assert_eq!(calls.borrow().len(), 2);
+        assert_call(
+            calls.borrow()[0],
+            (Point2D::new(60., 50.), Point2D::new(10., 0.), 2., 0.),
+        );
+        assert_call(
+            calls.borrow()[1],
+            (Point2D::new(50., 50.), Point2D::new(-10., 0.), 1.5, 0.),
+        );
+    }
+
+    #[test]
+    fn rotation() {
+        let (scope, calls) = scope();
+
+        // Quarter turn clockwise around the center, one pointer at a time.
+        press(&scope, Point2D::new(40., 50.), Point2D::new(60., 50.));
+        move_to(&scope, 1, Point2D::new(50., 60.));
+
+        assert!(calls.borrow().is_empty());
+
+        move_to(&scope, 0, Point2D::new(50., 40.));
+
+        assert_eq!(calls.borrow().len(), 1);
+        assert_call(
+            calls.borrow()[0],
+            (Point2D::new(50., 50.), Point2D::new(0., 0.), 1., 90.),
+        );
+    }
+
+    #[test]
+    fn release_resets() {
+        let (scope, calls) = scope();
+
+        press(&scope, Point2D::new(40., 50.), Point2D::new(60., 50.));
+        move_to(&scope, 1, Point2D::new(80., 50.));
+        dispatch(
+            &scope,
+            PointerEvent::release(Point2D::new(40., 50.)).with_id(0),
+            20,
+        );
+        dispatch(
+            &scope,
+            PointerEvent::release(Point2D::new(80., 50.)).with_id(1),
+            20,
+        );
+
+        // Below the slop again for the next gesture.
+        press(&scope, Point2D::new(40., 50.), Point2D::new(60., 50.));
+        move_to(&scope, 1, Point2D::new(64., 50.));
+
+        assert_eq!(calls.borrow().len(), 1);
+    }
+}
@@ -0,0 +1,128 @@
+use std::{collections::VecDeque, time::Duration};
+
+use rumpose_core::prelude::*;
+
+/// Only the movement of this recent period counts.
+const HORIZON: Duration = Duration::from_millis(100);
+/// A pause this long means the pointer stopped before the latest movement.
+const STOP_TIMEOUT: Duration = Duration::from_millis(40);
+const MAX_SAMPLES: usize = 20;
+
+/// Estimates the velocity of a pointer from its recent positions, e.g. to
+/// fling after a drag.
+#[derive(Debug, Default, Clone)]
+pub struct VelocityTracker {
+    samples: VecDeque<(Duration, Point2D)>,
+}
+
+impl VelocityTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the position of the pointer at `time`, as given by
+    /// [`PointerEvent::time`].
+    pub fn add_position(&mut self, time: Duration, position: Point2D) {
+        if self.samples.len() == MAX_SAMPLES {
+            self.samples.pop_front();
+        }
+
+        self.samples.push_back((time, position));
+    }
+
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Returns the velocity in pixels per second, fitting a line through the
+    /// recent positions with least squares.
+    #[must_use]
+    pub fn velocity(&self) -> Point2D {
+        let Some(&(latest, _)) = self.samples.back() else {
+            return Point2D::default();
+        };
+
+        let mut samples = Vec::new();
+        let mut previous = latest;
+
+        for &(time, position) in self.samples.iter().rev() {
+            if latest.saturating_sub(time) > HORIZON || previous.saturating_sub(time) > STOP_TIMEOUT
+            {
+                break;
+            }
+
+            // Subtracted as durations first, as large times lose precision
+            // as floats.
+            samples.push((-latest.saturating_sub(time).as_secs_f32(), position));
+            previous = time;
+        }
+
+        if samples.len() < 2 {
+            return Point2D::default();
+        }
+
+        let count = samples.len() as f32;
+        let mean_time = samples.iter().map(|(time, _)| time).sum::<f32>() / count;
+        let mean_x = samples.iter().map(|(_, position)| position.x).sum::<f32>() / count;
+        let mean_y = samples.iter().map(|(_, position)| position.y).sum::<f32>() / count;
+        let variance = samples
+            .iter()
+            .map(|(time, _)| (time - mean_time).powi(2))
+            .sum::<f32>();
+
+        if variance == 0. {
+            return Point2D::default();
+        }
+
+        let slope = |mean: f32, value: fn(&Point2D) -> f32| {
+            samples
+                .iter()
+                .map(|(time, position)| (time - mean_time) * (value(position) - mean))
+                .sum::<f32>()
+                / variance
+        };
+
+        Point2D::new(
+            slope(mean_x, |position| position.x),
+            slope(mean_y, |position| position.y),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steady_movement() {
+        let mut tracker = VelocityTracker::new();
+
+        // Far from the origin of the clock, where seconds as floats are too
+        // coarse for the intervals.
+        let start = Duration::from_secs(100_000);
+
+        for step in 0..5_u16 {
+            tracker.add_position(
+                start + Duration::from_millis(u64::from(step) * 10),
+                Point2D::new(f32::from(step) * 5., f32::from(step) * -2.),
+            );
+        }
+
+        let velocity = tracker.velocity();
+
+        assert!((velocity.x - 500.).abs() < 1., "{velocity:?}");
+        assert!((velocity.y + 200.).abs() < 1., "{velocity:?}");
+    }
+
+    #[test]
+    fn stopped_before_latest_movement() {
+        let mut tracker = VelocityTracker::new();
+
+        tracker.add_position(Duration::from_millis(0), Point2D::new(0., 0.));
+        tracker.add_position(Duration::from_millis(10), Point2D::new(50., 0.));
+        tracker.add_position(Duration::from_millis(90), Point2D::new(50., 0.));
+
+        assert_eq!(tracker.velocity(), Point2D::default());
+    }
+}
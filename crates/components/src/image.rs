@@ -0,0 +1,241 @@
+use std::{cell::RefCell, fs, path::PathBuf, rc::Rc};
+
+use rumpose_core::prelude::*;
+
+use crate::{ModifierElement, modified};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageSource {
+    Bytes(Rc<[u8]>),
+    File(PathBuf),
+    /// Image embedded into the binary, e.g. with [`include_bytes`].
+    Asset(&'static [u8]),
+}
+
+impl ImageSource {
+    #[must_use]
+    pub fn bytes(value: impl Into<Rc<[u8]>>) -> Self {
+        Self::Bytes(value.into())
+    }
+
+    #[must_use]
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self::File(path.into())
+    }
+
+    #[must_use]
+    pub const fn asset(value: &'static [u8]) -> Self {
+        Self::Asset(value)
+    }
+
+    /// Decodes the source, returning `None` if it cannot be read or is not a
+    /// supported image format.
+    #[must_use]
+    pub fn decode(&self) -> Option<ImageBitmap> {
+        match self {
+            Self::Bytes(bytes) => Image::from_encoded(Data::new_copy(bytes)),
+            Self::File(path) => Image::from_encoded(Data::new_copy(&fs::read(path).ok()?)),
+            Self::Asset(bytes) => Image::from_encoded(Data::new_copy(bytes)),
+        }
+        .map(ImageBitmap)
+    }
+}
+
+/// Decoded raster image.
+#[derive(Debug, Clone)]
+pub struct ImageBitmap(pub(crate) Image);
+
+impl ImageBitmap {
+    #[must_use]
+    pub fn size(&self) -> Size2D {
+        Size2D::new(self.0.width() as f32, self.0.height() as f32)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ContentScale {
+    /// Scales uniformly so that the content fits inside the bounds.
+    #[default]
+    Fit,
+    /// Scales uniformly so that the content covers the bounds.
+    Crop,
+    /// Scales each axis independently to fill the bounds.
+    FillBounds,
+    /// Same as [`Fit`](ContentScale::Fit), but never scales content up.
+    Inside,
+    None,
+}
+
+impl ContentScale {
+    /// Returns the horizontal and vertical scale factors for drawing content
+    /// of size `src` into `dst`.
+    #[must_use]
+    pub fn scale(self, src: Size2D, dst: Size2D) -> Point2D {
+        let horizontal = dst.width / src.width;
+        let vertical = dst.height / src.height;
+
+        match self {
+            Self::Fit => Point2D::from(horizontal.min(vertical)),
+            Self::Crop => Point2D::from(horizontal.max(vertical)),
+            Self::FillBounds => Point2D::new(horizontal, vertical),
+            Self::Inside => Point2D::from(horizontal.min(vertical).min(1.)),
+            Self::None => Point2D::from(1.),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ImageProps {
+    content_scale: ContentScale,
+    alignment: Alignment,
+    color_filter: Option<ColorFilter>,
+    alpha: f32,
+}
+
+impl Default for ImageProps {
+    fn default() -> Self {
+        Self {
+            content_scale: ContentScale::default(),
+            alignment: Alignment::default(),
+            color_filter: None,
+            alpha: 1.,
+        }
+    }
+}
+
+impl ImageProps {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn content_scale(mut self, value: ContentScale) -> Self {
+        self.content_scale = value;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn alignment(mut self, value: Alignment) -> Self {
+        self.alignment = value;
+
+        self
+    }
+
+    #[must_use]
+    pub fn color_filter(mut self, value: ColorFilter) -> Self {
+        self.color_filter = Some(value);
+
+        self
+    }
+
+    /// Tints every opaque pixel of the image with the given color.
+    #[must_use]
+    pub fn tint(mut self, color: Color) -> Self {
+        self.color_filter = color_filters::blend(color.to_engine(), BlendMode::SrcIn);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn alpha(mut self, value: f32) -> Self {
+        self.alpha = value.clamp(0., 1.);
+
+        self
+    }
+}
+
+impl ImageProps {
+    /// Returns the area content of size `intrinsic` occupies inside `area`.
+    pub(crate) fn place(&self, intrinsic: Size2D, area: Rect2D) -> Rect2D {
+        let scale = self.content_scale.scale(intrinsic, area.size);
+        let size = Size2D::new(intrinsic.width * scale.x, intrinsic.height * scale.y);
+
+        Rect2D::new(area.origin + self.alignment.align(size, area.size), size)
+    }
+
+    pub(crate) fn paint(&self) -> Paint {
+        let mut paint = Paint::default();
+
+        paint.set_anti_alias(true);
+        paint.set_alpha_f(self.alpha);
+        paint.set_color_filter(self.color_filter.clone());
+
+        paint
+    }
+}
+
+/// Measures content of size `intrinsic`, scaling it down uniformly to fit the
+/// constraints. Content without a size, e.g. an image that failed to decode,
+/// takes the minimum size.
+pub(crate) fn measure_intrinsic(intrinsic: Size2D, constraints: Constraints) -> Size2D {
+    if intrinsic.width <= 0. || intrinsic.height <= 0. {
+        return constraints.min;
+    }
+
+    let scale = (constraints.max.width / intrinsic.width)
+        .min(constraints.max.height / intrinsic.height)
+        .min(1.);
+
+    constraints.apply(intrinsic * scale)
+}
+
+#[track_caller]
+pub fn image(scope: Scope, modifier: impl ModifierElement, source: ImageSource, props: ImageProps) {
+    // Decoded again only when the source changes, not on every
+    // recomposition.
+    let cache = scope
+        .use_state(|| Rc::new(RefCell::new(None::<(ImageSource, Option<ImageBitmap>)>)))
+        .with(Clone::clone);
+    let image = {
+        let mut cache = cache.borrow_mut();
+
+        match &*cache {
+            Some((key, image)) if *key == source => image.clone(),
+            _ => {
+                let image = source.decode();
+
+                *cache = Some((source, image.clone()));
+
+                image
+            }
+        }
+    };
+
+    modified(scope, &modifier, move |scope| {
+        let image = image.clone();
+        let props = props.clone();
+        let intrinsic = image.as_ref().map(ImageBitmap::size).unwrap_or_default();
+
+        draw(
+            scope,
+            move |context| {
+                let Some(image) = &image else {
+                    return;
+                };
+
+                let area = context.area();
+
+                context.with_canvas(|canvas| {
+                    canvas.save();
+                    canvas.clip_rect(Rect::from(area), None, Some(true));
+                    canvas.draw_image_rect(
+                        &image.0,
+                        None,
+                        Rect::from(props.place(intrinsic, area)),
+                        &props.paint(),
+                    );
+                    canvas.restore();
+                });
+            },
+            move |scope| {
+                layout(
+                    scope,
+                    move |_, _, constraints| measure_intrinsic(intrinsic, constraints),
+                    |_| {},
+                );
+            },
+        );
+    });
+}
@@ -0,0 +1,85 @@
+use std::fmt::Debug;
+
+use rumpose_core::prelude::*;
+
+use crate::InteractionState;
+
+/// Visual feedback for the interactions with a component, drawn over its
+/// content.
+pub trait Indication: Debug {
+    fn draw(&self, context: &RenderContext, state: InteractionState);
+}
+
+/// Default indication: a translucent overlay while hovered or focused and a
+/// circle spreading from the press position while pressed, clipped to the
+/// component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ripple {
+    pub color: Color,
+}
+
+impl Default for Ripple {
+    fn default() -> Self {
+        Self::new(Color::BLACK)
+    }
+}
+
+impl Ripple {
+    const FOCUS_ALPHA: f32 = 0.12;
+    const HOVER_ALPHA: f32 = 0.08;
+    const PRESS_ALPHA: f32 = 0.12;
+
+    #[must_use]
+    pub const fn new(color: Color) -> Self {
+        Self { color }
+    }
+
+    fn paint(&self, alpha: f32) -> graphics::Paint {
+        graphics::Paint::fill(self.color.with_alpha(alpha))
+    }
+}
+
+impl Indication for Ripple {
+    fn draw(&self, context: &RenderContext, state: InteractionState) {
+        let alpha = if state.focused {
+            Self::FOCUS_ALPHA
+        } else if state.hovered {
+            Self::HOVER_ALPHA
+        } else {
+            0.
+        };
+
+        if alpha == 0. && state.pressed.is_none() {
+            return;
+        }
+
+        let area = context.area();
+
+        context.with_graphics(|canvas| {
+            canvas.save();
+            canvas.clip_rect(area.into());
+
+            if alpha > 0. {
+                canvas.draw_rect(area.into(), &self.paint(alpha));
+            }
+
+            if let Some(position) = state.pressed {
+                // Reaches the farthest corner, so the circle covers the
+                // whole component.
+                let radius = position
+                    .x
+                    .max(area.size.width - position.x)
+                    .hypot(position.y.max(area.size.height - position.y));
+
+                canvas.draw_circle(
+                    area.origin.x + position.x,
+                    area.origin.y + position.y,
+                    radius,
+                    &self.paint(Self::PRESS_ALPHA),
+                );
+            }
+
+            canvas.restore();
+        });
+    }
+}
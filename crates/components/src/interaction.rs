@@ -0,0 +1,103 @@
+use rumpose_core::prelude::*;
+
+/// Change in how the user interacts with a component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Interaction {
+    /// Pressed at the position, relative to the component.
+    Press(Point2D),
+    /// The press ended on the component, e.g. by clicking it.
+    Release,
+    /// The press ended without a click, e.g. by leaving the component.
+    Cancel,
+    HoverEnter,
+    HoverExit,
+    Focus,
+    Unfocus,
+}
+
+/// Interactions in progress, folded from the emitted [`Interaction`]s.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct InteractionState {
+    /// Position of the press in progress, relative to the component.
+    pub pressed: Option<Point2D>,
+    pub hovered: bool,
+    pub focused: bool,
+}
+
+impl InteractionState {
+    #[must_use]
+    pub const fn is_pressed(&self) -> bool {
+        self.pressed.is_some()
+    }
+
+    fn apply(mut self, interaction: Interaction) -> Self {
+        match interaction {
+            Interaction::Press(position) => self.pressed = Some(position),
+            Interaction::Release | Interaction::Cancel => self.pressed = None,
+            Interaction::HoverEnter => self.hovered = true,
+            Interaction::HoverExit => self.hovered = false,
+            Interaction::Focus => self.focused = true,
+            Interaction::Unfocus => self.focused = false,
+        }
+
+        self
+    }
+}
+
+/// Stream of [`Interaction`]s shared between the components emitting them,
+/// such as [`clickable`](crate::ClickableModifierExt::clickable), and the
+/// ones reacting to them, such as an [`Indication`](crate::Indication).
+/// Reading it while composing recomposes the reader on every interaction.
+#[derive(Clone)]
+pub struct MutableInteractionSource {
+    state: State<InteractionState>,
+    last: State<Option<Interaction>>,
+}
+
+impl MutableInteractionSource {
+    #[track_caller]
+    #[must_use]
+    pub fn new(scope: Scope) -> Self {
+        Self {
+            state: scope.use_state(InteractionState::default),
+            last: scope.use_state(|| None),
+        }
+    }
+
+    pub fn emit(&self, interaction: Interaction) {
+        let state = self.state.with(Clone::clone);
+        let next = state.apply(interaction);
+
+        if next != state {
+            self.state.set(next);
+        }
+
+        self.last.set(Some(interaction));
+    }
+
+    #[must_use]
+    pub fn state(&self) -> InteractionState {
+        self.state.with(Clone::clone)
+    }
+
+    /// Returns the last emitted interaction.
+    #[must_use]
+    pub fn last_interaction(&self) -> Option<Interaction> {
+        self.last.with(Clone::clone)
+    }
+
+    #[must_use]
+    pub fn is_pressed(&self) -> bool {
+        self.state().is_pressed()
+    }
+
+    #[must_use]
+    pub fn is_hovered(&self) -> bool {
+        self.state().hovered
+    }
+
+    #[must_use]
+    pub fn is_focused(&self) -> bool {
+        self.state().focused
+    }
+}
@@ -0,0 +1,100 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rumpose_core::prelude::*;
+
+use crate::{ModifierElement, Orientation, ScrollState, ScrollableModifier, modified};
+
+/// Column composing only the items visible in its height, scrolled by
+/// `state` on drags and wheel events like
+/// [`vertical_scroll`](crate::ScrollModifierExt::vertical_scroll). Items
+/// leaving the viewport are disposed. The end of the list, and so
+/// [`ScrollState::max_value`], is only known once the last item was
+/// measured.
+#[track_caller]
+pub fn lazy_column<I: Fn(Scope, usize) + Clone + 'static>(
+    scope: Scope,
+    modifier: impl ModifierElement,
+    state: ScrollState,
+    items_count: usize,
+    item_builder: I,
+) {
+    // Heights of the measured items, so the ones above the viewport are
+    // skipped without composing them.
+    let heights = scope
+        .use_state(|| Rc::new(RefCell::new(Vec::<Option<f32>>::new())))
+        .with(Clone::clone);
+    let scrollable = ScrollableModifier::new(state.clone(), Orientation::Vertical);
+
+    modified(scope, &modifier.then(scrollable), move |scope| {
+        let state = state.clone();
+        let heights = heights.clone();
+        let item_builder = item_builder.clone();
+        // Read while composing, so the items are composed again on every
+        // scroll.
+        let value = state.value();
+
+        subcompose_layout(
+            scope,
+            move |_, layout_context, constraints, compose_context| {
+                let mut heights = heights.borrow_mut();
+                // Clamped to the end found by the last measure, which is only
+                // stored by this one so the items are not composed again.
+                let value = value.min(state.max_value());
+                let end = value + constraints.max.height;
+                let item_constraints =
+                    Constraints::new(0., constraints.max.width, 0., f32::INFINITY);
+                let mut visible = Vec::new();
+                let mut width = constraints.min.width;
+                let mut y = 0.;
+
+                heights.resize(items_count, None);
+
+                for item in 0..items_count {
+                    if y >= end {
+                        break;
+                    }
+
+                    if let Some(height) = heights[item].filter(|height| y + height <= value) {
+                        y += height;
+
+                        continue;
+                    }
+
+                    let item_builder = item_builder.clone();
+                    let areas = compose_context.compose(
+                        item,
+                        move |scope| item_builder(scope, item),
+                        layout_context,
+                        constraints,
+                        |layout_context, id, _| (id, layout_context.measure(id, item_constraints)),
+                    );
+                    let top = y;
+
+                    // Relative to the list, which moves its items when it is
+                    // placed.
+                    for (id, area) in areas {
+                        compose_context.place(item, id, 0., y - value);
+
+                        width = width.max(area.width);
+                        y += area.height;
+                    }
+
+                    heights[item] = Some(y - top);
+
+                    if y > value {
+                        visible.push(item);
+                    }
+                }
+
+                compose_context.retain(|item| visible.contains(&item));
+
+                let size = constraints.apply(Size2D::new(width, y - value));
+                let total = heights.iter().copied().sum::<Option<f32>>();
+
+                state.set_max_value(total.map_or(f32::INFINITY, |total| total - size.height));
+
+                size
+            },
+        );
+    });
+}
@@ -1,9 +1,46 @@
+mod brush;
+mod canvas;
+mod clipboard;
 mod container;
+mod gesture;
+mod image;
+mod indication;
+mod interaction;
+mod lazy;
 mod modifier;
+mod nested_scroll;
+mod scroll;
+mod selection;
 mod text;
+mod text_field;
+mod vector;
 
 pub use self::{
+    brush::Brush,
+    canvas::{DrawScope, DrawStyle, DrawTransform, Stroke, StrokeCap, StrokeJoin, canvas},
+    clipboard::{Clipboard, MemoryClipboard},
     container::{column, container},
+    gesture::{DragGestures, Orientation, PointerInputScope, TapGestures, VelocityTracker},
+    image::{ContentScale, ImageBitmap, ImageProps, ImageSource, image},
+    indication::{Indication, Ripple},
+    interaction::{Interaction, InteractionState, MutableInteractionSource},
+    lazy::lazy_column,
     modifier::*,
-    text::{TextProps, text},
+    nested_scroll::{NestedScrollConnection, NestedScrollDispatcher, NestedScrollSource},
+    scroll::ScrollState,
+    selection::{SelectionContainerProps, SelectionState, selection_container},
+    text::{
+        AnnotatedRange, AnnotatedString, AnnotatedStringBuilder, FontFamily, SpanStyle,
+        StringAnnotation, TextLayoutResult, TextLine, TextOverflow, TextParagraphStyle, TextProps,
+        text,
+    },
+    text_field::{
+        ImeAction, KeyboardCapitalization, KeyboardOptions, KeyboardType,
+        PasswordVisualTransformation, TextFieldProps, TextFieldValue, TextRange,
+        VisualTransformation, basic_text_field,
+    },
+    vector::{
+        ImageVector, ImageVectorBuilder, PathData, PathDataBuilder, VectorSource, icon,
+        vector_image,
+    },
 };
@@ -0,0 +1,277 @@
+use std::{fmt::Debug, rc::Rc};
+
+use rumpose_core::prelude::*;
+
+use super::{FocusModifierExt, FocusableModifier, ModifierElement};
+use crate::{
+    Indication, Interaction, MutableInteractionSource, Ripple, TapGestures, gesture::TapDetector,
+};
+
+#[derive(Clone)]
+pub struct ClickableProps {
+    enabled: bool,
+    on_click: Rc<dyn Fn()>,
+    on_long_click: Option<Rc<dyn Fn()>>,
+    on_double_click: Option<Rc<dyn Fn()>>,
+    interaction_source: Option<MutableInteractionSource>,
+    indication: Option<Rc<dyn Indication>>,
+}
+
+impl ClickableProps {
+    #[must_use]
+    pub fn new<F: Fn() + 'static>(on_click: F) -> Self {
+        Self {
+            enabled: true,
+            on_click: Rc::new(on_click),
+            on_long_click: None,
+            on_double_click: None,
+            interaction_source: None,
+            indication: Some(Rc::new(Ripple::default())),
+        }
+    }
+
+    /// Disabled components ignore pointers and emit no interactions.
+    #[must_use]
+    pub const fn enabled(mut self, value: bool) -> Self {
+        self.enabled = value;
+
+        self
+    }
+
+    /// Called instead of the click when the press lasts long enough, on the
+    /// first frame or pointer event past the timeout.
+    #[must_use]
+    pub fn on_long_click<F: Fn() + 'static>(mut self, callback: F) -> Self {
+        self.on_long_click = Some(Rc::new(callback));
+
+        self
+    }
+
+    /// Called instead of the click for a click following the previous one
+    /// quickly enough. The first click is still reported.
+    #[must_use]
+    pub fn on_double_click<F: Fn() + 'static>(mut self, callback: F) -> Self {
+        self.on_double_click = Some(Rc::new(callback));
+
+        self
+    }
+
+    /// Source the interactions are emitted to, created for the component if
+    /// not set.
+    #[must_use]
+    pub fn interaction_source(mut self, value: MutableInteractionSource) -> Self {
+        self.interaction_source = Some(value);
+
+        self
+    }
+
+    /// Indication drawn over the content, [`Ripple`] by default.
+    #[must_use]
+    pub fn indication(mut self, value: impl Indication + 'static) -> Self {
+        self.indication = Some(Rc::new(value));
+
+        self
+    }
+
+    #[must_use]
+    pub fn no_indication(mut self) -> Self {
+        self.indication = None;
+
+        self
+    }
+
+    /// Clicks the focused component on enter or space.
+    fn on_key(&self, event: &KeyEvent) -> bool {
+        if !self.enabled
+            || event.kind != KeyEventType::KeyDown
+            || !matches!(event.key, Key::Enter | Key::Character(' '))
+        {
+            return false;
+        }
+
+        (self.on_click)();
+
+        true
+    }
+
+    /// Detects clicks as taps, emitting the press and its end to `source`.
+    fn gestures(&self, source: &MutableInteractionSource) -> TapGestures {
+        let press = source.clone();
+        let release = source.clone();
+        let cancel = source.clone();
+        let on_click = self.on_click.clone();
+        let mut gestures = TapGestures::new()
+            .on_press(move |position| press.emit(Interaction::Press(position)))
+            .on_tap(move |_| on_click())
+            .on_release(move || release.emit(Interaction::Release))
+            .on_cancel(move || cancel.emit(Interaction::Cancel));
+
+        if let Some(on_long_click) = self.on_long_click.clone() {
+            gestures = gestures.on_long_press(move |_| on_long_click());
+        }
+
+        if let Some(on_double_click) = self.on_double_click.clone() {
+            gestures = gestures.on_double_tap(move |_| on_double_click());
+        }
+
+        gestures
+    }
+
+    fn on_pointer(
+        &self,
+        event: &PointerEvent,
+        pass: PointerEventPass,
+        detector: &Rc<TapDetector>,
+        source: &MutableInteractionSource,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        match (pass, event.kind) {
+            (PointerEventPass::Main, PointerEventType::Enter) => {
+                source.emit(Interaction::HoverEnter);
+            }
+            (PointerEventPass::Main, PointerEventType::Exit) => {
+                source.emit(Interaction::HoverExit);
+            }
+            _ => detector.on_event(event, pass),
+        }
+    }
+}
+
+/// Clickable content, also focusable with the requesters and observers of the
+/// modifiers before it in the chain.
+#[derive(Clone)]
+pub struct ClickableModifier(ClickableProps, FocusProps);
+
+impl Debug for ClickableModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClickableModifier")
+            .field("enabled", &self.0.enabled)
+            .field("focus", &self.1)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ModifierElement for ClickableModifier {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        let props = self.0.clone();
+        let source = match &props.interaction_source {
+            Some(source) => source.clone(),
+            None => MutableInteractionSource::new(scope),
+        };
+        // The detector keeps the press across recompositions, with the
+        // callbacks of the latest one.
+        let detector = scope
+            .use_state(|| TapDetector::new(TapGestures::new()))
+            .with(Clone::clone);
+
+        detector.set_gestures(props.gestures(&source));
+
+        // Read while composing, so the indication is drawn again on every
+        // interaction.
+        let interactions = source.state();
+        let indication = props.indication.clone();
+        let key_props = props.clone();
+        let pointer_source = source.clone();
+
+        // The focus is emitted to the same source, so the indication shows
+        // it.
+        FocusableModifier::new(props.enabled, Some(source), self.1.clone())
+            .on_key_event(move |event| key_props.on_key(event))
+            .apply(
+                scope,
+                Rc::new(move |scope| {
+                    let props = props.clone();
+                    let detector = detector.clone();
+                    let source = pointer_source.clone();
+                    let indication = indication.clone();
+                    let content = content.clone();
+
+                    pointer_input(
+                        scope,
+                        move |event, pass| props.on_pointer(event, pass, &detector, &source),
+                        move |scope| {
+                            let indication = indication.clone();
+                            let content = content.clone();
+
+                            draw(
+                                scope,
+                                move |context| {
+                                    context.set_do_content_draw(false);
+                                    context.draw_content();
+
+                                    if let Some(indication) = &indication {
+                                        indication.draw(context, interactions);
+                                    }
+                                },
+                                move |scope| content(scope),
+                            );
+                        },
+                    );
+                }),
+            );
+    }
+
+    /// The requesters and observers before it were taken by its target.
+    fn focus_props(&self, _props: FocusProps) -> FocusProps {
+        FocusProps::new()
+    }
+}
+
+pub trait ClickableModifierExt {
+    fn clickable<F: Fn() + 'static>(self, on_click: F) -> impl ModifierElement;
+    fn clickable_with(self, props: ClickableProps) -> impl ModifierElement;
+    fn combined_clickable<F: Fn() + 'static, L: Fn() + 'static, D: Fn() + 'static>(
+        self,
+        on_click: F,
+        on_long_click: L,
+        on_double_click: D,
+    ) -> impl ModifierElement;
+    /// Makes the content toggle `value` when clicked, passing the new value
+    /// to `on_value_change`.
+    fn toggleable<F: Fn(bool) + 'static>(
+        self,
+        value: bool,
+        on_value_change: F,
+    ) -> impl ModifierElement;
+}
+
+impl<T: ModifierElement> ClickableModifierExt for T {
+    #[track_caller]
+    fn clickable<F: Fn() + 'static>(self, on_click: F) -> impl ModifierElement {
+        self.clickable_with(ClickableProps::new(on_click))
+    }
+
+    #[track_caller]
+    fn clickable_with(self, props: ClickableProps) -> impl ModifierElement {
+        let focus = self.focus_props(FocusProps::new());
+
+        self.then(ClickableModifier(props, focus))
+    }
+
+    #[track_caller]
+    fn combined_clickable<F: Fn() + 'static, L: Fn() + 'static, D: Fn() + 'static>(
+        self,
+        on_click: F,
+        on_long_click: L,
+        on_double_click: D,
+    ) -> impl ModifierElement {
+        self.clickable_with(
+            ClickableProps::new(on_click)
+                .on_long_click(on_long_click)
+                .on_double_click(on_double_click),
+        )
+    }
+
+    #[track_caller]
+    fn toggleable<F: Fn(bool) + 'static>(
+        self,
+        value: bool,
+        on_value_change: F,
+    ) -> impl ModifierElement {
+        self.clickable(move || on_value_change(!value))
+    }
+}
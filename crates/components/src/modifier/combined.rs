@@ -25,4 +25,8 @@ impl<L: ModifierElement, R: ModifierElement + 'static> ModifierElement for Combi
             }),
         );
     }
+
+    fn focus_props(&self, props: FocusProps) -> FocusProps {
+        self.1.focus_props(self.0.focus_props(props))
+    }
 }
@@ -1,26 +1,58 @@
 use rumpose_core::prelude::*;
 
-use super::{DrawModifierExt, ModifierElement};
+use super::{DrawModifierExt, ModifierElement, Shape};
+use crate::Brush;
 
 pub trait ContentModifierExt {
-    fn background(self, color: Color) -> impl ModifierElement;
+    fn background(self, brush: impl Into<Brush>) -> impl ModifierElement;
+    fn background_shape<S: Shape + 'static>(
+        self,
+        brush: impl Into<Brush>,
+        shape: S,
+    ) -> impl ModifierElement;
 }
 
 impl<T: ModifierElement> ContentModifierExt for T {
     #[track_caller]
-    fn background(self, color: Color) -> impl ModifierElement {
+    fn background(self, brush: impl Into<Brush>) -> impl ModifierElement {
+        let brush = brush.into();
+
         self.draw(move |context| {
             let area = context.area();
 
-            let mut paint = Paint::default();
+            if let Brush::Solid(color) = brush {
+                context.with_graphics(|canvas| {
+                    canvas.draw_rect(area.into(), &graphics::Paint::fill(color));
+                });
+            } else {
+                context.with_canvas(|canvas| {
+                    canvas.draw_rect(Rect::from(area), &brush.to_paint(area));
+                });
+            }
+        })
+    }
 
-            paint.set_anti_alias(true);
-            paint.set_color(color);
+    #[track_caller]
+    fn background_shape<S: Shape + 'static>(
+        self,
+        brush: impl Into<Brush>,
+        shape: S,
+    ) -> impl ModifierElement {
+        let brush = brush.into();
+
+        self.draw(move |context| {
+            let area = context.area();
+            let path = shape.create(area);
 
-            context
-                .surface()
-                .canvas()
-                .draw_rect(Rect::from(area), &paint);
+            if let Brush::Solid(color) = brush {
+                context.with_graphics(|canvas| {
+                    canvas.draw_path(&to_graphics_path(&path), &graphics::Paint::fill(color));
+                });
+            } else {
+                context.with_canvas(|canvas| {
+                    canvas.draw_path(&path, &brush.to_paint(area));
+                });
+            }
         })
     }
 }
@@ -34,28 +34,13 @@ impl ModifierElement for DrawModifier {
 pub struct ClipModifier(Rc<dyn Shape>);
 
 impl ModifierElement for ClipModifier {
+    #[track_caller]
     fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
         let shape = self.0.clone();
 
-        rumpose_core::prelude::draw(
+        layer(
             scope,
-            move |context| {
-                context.set_do_content_draw(false);
-
-                let path = shape.create(context.area());
-
-                {
-                    let mut surface = context.surface();
-                    let canvas = surface.canvas();
-
-                    canvas.save();
-                    canvas.clip_path(&path, Some(ClipOp::Intersect), Some(true));
-                }
-
-                context.draw_content();
-
-                context.surface().canvas().restore();
-            },
+            LayerProps::new().clip(move |area| shape.create(area)),
             move |scope| content(scope),
         );
     }
@@ -78,27 +63,39 @@ impl Shape for CircleShape {
     }
 }
 
-#[derive(Debug)]
-pub struct RoundedShape {
-    top_start: f32,
-    top_end: f32,
-    bottom_start: f32,
-    bottom_end: f32,
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CornerSize {
+    Fixed(f32),
+    /// Percent of the smaller dimension of the shape.
+    Percent(f32),
 }
 
-impl RoundedShape {
+impl CornerSize {
     #[must_use]
-    pub const fn new(top_start: f32, top_end: f32, bottom_start: f32, bottom_end: f32) -> Self {
-        Self {
-            top_start,
-            top_end,
-            bottom_start,
-            bottom_end,
+    pub fn resolve(self, size: Size2D) -> f32 {
+        match self {
+            Self::Fixed(value) => value.max(0.),
+            Self::Percent(percent) => size.width.min(size.height) * percent.clamp(0., 100.) / 100.,
         }
     }
+}
 
-    #[must_use]
-    pub const fn new_all(value: f32) -> Self {
+impl From<f32> for CornerSize {
+    fn from(value: f32) -> Self {
+        Self::Fixed(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Corners {
+    top_start: CornerSize,
+    top_end: CornerSize,
+    bottom_start: CornerSize,
+    bottom_end: CornerSize,
+}
+
+impl Corners {
+    const fn all(value: CornerSize) -> Self {
         Self {
             top_start: value,
             top_end: value,
@@ -106,35 +103,178 @@ impl RoundedShape {
             bottom_end: value,
         }
     }
+
+    /// Returns radii in clockwise order starting from the top start corner,
+    /// scaled down so that adjacent corners never overlap.
+    fn resolve(&self, size: Size2D) -> [f32; 4] {
+        let radii = [
+            self.top_start.resolve(size),
+            self.top_end.resolve(size),
+            self.bottom_end.resolve(size),
+            self.bottom_start.resolve(size),
+        ];
+
+        let scale = [
+            size.width / (radii[0] + radii[1]),
+            size.height / (radii[1] + radii[2]),
+            size.width / (radii[2] + radii[3]),
+            size.height / (radii[3] + radii[0]),
+        ]
+        .into_iter()
+        .filter(|value| value.is_finite())
+        .fold(1f32, f32::min);
+
+        radii.map(|value| value * scale)
+    }
 }
 
-impl Shape for RoundedShape {
-    fn create(&self, area: Rect2D) -> Path {
-        let &Self {
+#[derive(Debug)]
+pub struct RoundedShape(Corners);
+
+impl RoundedShape {
+    #[must_use]
+    pub const fn new(top_start: f32, top_end: f32, bottom_start: f32, bottom_end: f32) -> Self {
+        Self::from_corners(
+            CornerSize::Fixed(top_start),
+            CornerSize::Fixed(top_end),
+            CornerSize::Fixed(bottom_start),
+            CornerSize::Fixed(bottom_end),
+        )
+    }
+
+    #[must_use]
+    pub const fn new_all(value: f32) -> Self {
+        Self(Corners::all(CornerSize::Fixed(value)))
+    }
+
+    #[must_use]
+    pub const fn new_all_percent(percent: f32) -> Self {
+        Self(Corners::all(CornerSize::Percent(percent)))
+    }
+
+    #[must_use]
+    pub const fn from_corners(
+        top_start: CornerSize,
+        top_end: CornerSize,
+        bottom_start: CornerSize,
+        bottom_end: CornerSize,
+    ) -> Self {
+        Self(Corners {
             top_start,
             top_end,
             bottom_start,
             bottom_end,
-        } = self;
+        })
+    }
+}
 
-        if top_start + top_end + bottom_start + bottom_end == 0.0 {
+impl Shape for RoundedShape {
+    fn create(&self, area: Rect2D) -> Path {
+        let radii = self.0.resolve(area.size);
+
+        if radii.iter().all(|&value| value == 0.0) {
             Path::rect(Rect::from(area), None)
         } else {
             Path::rrect(
-                RRect::new_rect_radii(Rect::from(area), &[
-                    Point::new(self.top_start, self.top_start),
-                    Point::new(self.top_end, self.top_end),
-                    Point::new(self.bottom_start, self.bottom_start),
-                    Point::new(self.bottom_end, self.bottom_end),
-                ]),
+                RRect::new_rect_radii(
+                    Rect::from(area),
+                    &radii.map(|value| Point::new(value, value)),
+                ),
                 None,
             )
         }
     }
 }
 
+#[derive(Debug)]
+pub struct CutCornerShape(Corners);
+
+impl CutCornerShape {
+    #[must_use]
+    pub const fn new(top_start: f32, top_end: f32, bottom_start: f32, bottom_end: f32) -> Self {
+        Self::from_corners(
+            CornerSize::Fixed(top_start),
+            CornerSize::Fixed(top_end),
+            CornerSize::Fixed(bottom_start),
+            CornerSize::Fixed(bottom_end),
+        )
+    }
+
+    #[must_use]
+    pub const fn new_all(value: f32) -> Self {
+        Self(Corners::all(CornerSize::Fixed(value)))
+    }
+
+    #[must_use]
+    pub const fn new_all_percent(percent: f32) -> Self {
+        Self(Corners::all(CornerSize::Percent(percent)))
+    }
+
+    #[must_use]
+    pub const fn from_corners(
+        top_start: CornerSize,
+        top_end: CornerSize,
+        bottom_start: CornerSize,
+        bottom_end: CornerSize,
+    ) -> Self {
+        Self(Corners {
+            top_start,
+            top_end,
+            bottom_start,
+            bottom_end,
+        })
+    }
+}
+
+impl Shape for CutCornerShape {
+    fn create(&self, area: Rect2D) -> Path {
+        let [top_start, top_end, bottom_end, bottom_start] = self.0.resolve(area.size);
+        let (left, top, right, bottom) = (area.min_x(), area.min_y(), area.max_x(), area.max_y());
+
+        Path::polygon(
+            &[
+                Point::new(left + top_start, top),
+                Point::new(right - top_end, top),
+                Point::new(right, top + top_end),
+                Point::new(right, bottom - bottom_end),
+                Point::new(right - bottom_end, bottom),
+                Point::new(left + bottom_start, bottom),
+                Point::new(left, bottom - bottom_start),
+                Point::new(left, top + top_start),
+            ],
+            true,
+            None,
+            None,
+        )
+    }
+}
+
+/// Shape built by a closure receiving the area to outline.
+#[derive(Clone)]
+pub struct GenericShape(Rc<dyn Fn(Rect2D) -> Path>);
+
+impl GenericShape {
+    pub fn new<F: Fn(Rect2D) -> Path + 'static>(builder: F) -> Self {
+        Self(Rc::new(builder))
+    }
+}
+
+impl Debug for GenericShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("GenericShape")
+            .field(&Rc::as_ptr(&self.0))
+            .finish()
+    }
+}
+
+impl Shape for GenericShape {
+    fn create(&self, area: Rect2D) -> Path {
+        (self.0)(area)
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct BorderModifier(f32, Color, Rc<dyn Shape>);
+pub struct BorderModifier(Dp, Color, Rc<dyn Shape>);
 
 impl ModifierElement for BorderModifier {
     fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
@@ -143,6 +283,7 @@ impl ModifierElement for BorderModifier {
         rumpose_core::prelude::draw(
             scope,
             move |context| {
+                let width = width.to_px(context.density());
                 let path = shape.create({
                     let mut area = context.area();
 
@@ -152,16 +293,13 @@ impl ModifierElement for BorderModifier {
                     area
                 });
 
-                let mut paint = Paint::default();
-
-                paint.set_anti_alias(true);
-                paint.set_color(color);
-                paint.set_stroke(true);
-                paint.set_stroke_width(width);
+                let path = to_graphics_path(&path);
 
                 context.set_do_content_draw(false);
                 context.draw_content();
-                context.surface().canvas().draw_path(&path, &paint);
+                context.with_graphics(|canvas| {
+                    canvas.draw_path(&path, &graphics::Paint::stroke(color, width));
+                });
             },
             move |scope| {
                 let content = content.clone();
@@ -169,6 +307,7 @@ impl ModifierElement for BorderModifier {
                 layout(
                     scope,
                     move |node, context, constraints| {
+                        let width = width.to_px(context.density());
                         let mut size = context.measure(
                             node.children[0],
                             constraints.offset(-(width * 2.), -(width * 2.)),
@@ -190,8 +329,12 @@ impl ModifierElement for BorderModifier {
 pub trait DrawModifierExt {
     fn draw<F: Fn(&RenderContext) + 'static>(self, func: F) -> impl ModifierElement;
     fn clip<S: Shape + 'static>(self, shape: S) -> impl ModifierElement;
-    fn border<S: Shape + 'static>(self, width: f32, color: Color, shape: S)
-    -> impl ModifierElement;
+    fn border<S: Shape + 'static>(
+        self,
+        width: impl Into<Dp>,
+        color: Color,
+        shape: S,
+    ) -> impl ModifierElement;
 }
 
 impl<T: ModifierElement> DrawModifierExt for T {
@@ -210,10 +353,10 @@ impl<T: ModifierElement> DrawModifierExt for T {
     #[track_caller]
     fn border<S: Shape + 'static>(
         self,
-        width: f32,
+        width: impl Into<Dp>,
         color: Color,
         shape: S,
     ) -> impl ModifierElement {
-        self.then(BorderModifier(width, color, Rc::new(shape)))
+        self.then(BorderModifier(width.into(), color, Rc::new(shape)))
     }
 }
@@ -0,0 +1,238 @@
+use std::{fmt::Debug, rc::Rc};
+
+use rumpose_core::prelude::*;
+
+use super::ModifierElement;
+use crate::{Interaction, MutableInteractionSource};
+
+#[derive(Clone)]
+pub struct FocusableModifier {
+    enabled: bool,
+    interaction_source: Option<MutableInteractionSource>,
+    /// Requesters and observers of the modifiers before it in the chain.
+    focus: FocusProps,
+}
+
+impl FocusableModifier {
+    pub(crate) const fn new(
+        enabled: bool,
+        interaction_source: Option<MutableInteractionSource>,
+        focus: FocusProps,
+    ) -> Self {
+        Self {
+            enabled,
+            interaction_source,
+            focus,
+        }
+    }
+}
+
+impl Debug for FocusableModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FocusableModifier")
+            .field("enabled", &self.enabled)
+            .field("focus", &self.focus)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ModifierElement for FocusableModifier {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        let enabled = self.enabled;
+        let source = self.interaction_source.clone();
+        let requester = FocusRequester::new(scope);
+        let props = self
+            .focus
+            .clone()
+            .requester(requester.clone())
+            .can_focus(enabled)
+            .on_focus_changed(move |focused| {
+                if let Some(source) = &source {
+                    source.emit(if focused {
+                        Interaction::Focus
+                    } else {
+                        Interaction::Unfocus
+                    });
+                }
+            });
+
+        focus_target(scope, props, move |scope| {
+            let requester = requester.clone();
+            let content = content.clone();
+
+            pointer_input(
+                scope,
+                move |event, pass| {
+                    // The initial pass runs from the root down, so the
+                    // innermost focusable under the pointer keeps the focus.
+                    if enabled
+                        && pass == PointerEventPass::Initial
+                        && event.kind == PointerEventType::Press
+                    {
+                        requester.request_focus();
+                    }
+                },
+                move |scope| content(scope),
+            );
+        });
+    }
+
+    /// The requesters and observers before it were taken by this target.
+    fn focus_props(&self, _props: FocusProps) -> FocusProps {
+        FocusProps::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FocusRequesterModifier(FocusRequester);
+
+impl ModifierElement for FocusRequesterModifier {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        content(scope);
+    }
+
+    fn focus_props(&self, props: FocusProps) -> FocusProps {
+        props.requester(self.0.clone())
+    }
+}
+
+#[derive(Clone)]
+pub struct FocusObserverModifier(Rc<dyn Fn(bool)>);
+
+impl Debug for FocusObserverModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FocusObserverModifier")
+            .field(&Rc::as_ptr(&self.0))
+            .finish()
+    }
+}
+
+impl ModifierElement for FocusObserverModifier {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        content(scope);
+    }
+
+    fn focus_props(&self, props: FocusProps) -> FocusProps {
+        let callback = self.0.clone();
+
+        props.on_focus_changed(move |focused| callback(focused))
+    }
+}
+
+#[derive(Clone)]
+pub struct KeyEventModifier {
+    handler: KeyHandler,
+    preview: bool,
+}
+
+impl Debug for KeyEventModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyEventModifier")
+            .field("preview", &self.preview)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ModifierElement for KeyEventModifier {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        let handler = self.handler.clone();
+
+        if self.preview {
+            preview_key_input(
+                scope,
+                move |event| handler(event),
+                move |scope| content(scope),
+            );
+        } else {
+            key_input(
+                scope,
+                move |event| handler(event),
+                move |scope| content(scope),
+            );
+        }
+    }
+}
+
+pub trait FocusModifierExt {
+    /// Makes the modified content focusable by clicks, the tab and arrow
+    /// keys, and [`focus_requester`](FocusModifierExt::focus_requester).
+    fn focusable(self) -> impl ModifierElement;
+    /// Like [`focusable`](FocusModifierExt::focusable), emitting
+    /// [`Interaction::Focus`] and [`Interaction::Unfocus`] to
+    /// `interaction_source`, so indications can show the focus.
+    fn focusable_with(
+        self,
+        enabled: bool,
+        interaction_source: Option<MutableInteractionSource>,
+    ) -> impl ModifierElement;
+    /// Attaches `requester` to the next
+    /// [`focusable`](FocusModifierExt::focusable) or
+    /// [`clickable`](crate::ClickableModifierExt::clickable) in the chain.
+    /// Components such as text fields take requesters in their props.
+    fn focus_requester(self, requester: FocusRequester) -> impl ModifierElement;
+    /// Calls `callback` when the next
+    /// [`focusable`](FocusModifierExt::focusable) or
+    /// [`clickable`](crate::ClickableModifierExt::clickable) in the chain
+    /// gains or loses the focus.
+    fn on_focus_changed<F: Fn(bool) + 'static>(self, callback: F) -> impl ModifierElement;
+    /// Passes the key events to `handler` while the modified content has the
+    /// focus, after the focused node and the handlers inside it.
+    fn on_key_event<F: Fn(&KeyEvent) -> bool + 'static>(self, handler: F) -> impl ModifierElement;
+    /// Passes the key events to `handler` while the modified content has the
+    /// focus, before the focused node and the handlers inside it.
+    fn on_preview_key_event<F: Fn(&KeyEvent) -> bool + 'static>(
+        self,
+        handler: F,
+    ) -> impl ModifierElement;
+}
+
+impl<T: ModifierElement> FocusModifierExt for T {
+    #[track_caller]
+    fn focusable(self) -> impl ModifierElement {
+        self.focusable_with(true, None)
+    }
+
+    #[track_caller]
+    fn focusable_with(
+        self,
+        enabled: bool,
+        interaction_source: Option<MutableInteractionSource>,
+    ) -> impl ModifierElement {
+        let focus = self.focus_props(FocusProps::new());
+
+        self.then(FocusableModifier::new(enabled, interaction_source, focus))
+    }
+
+    #[track_caller]
+    fn focus_requester(self, requester: FocusRequester) -> impl ModifierElement {
+        self.then(FocusRequesterModifier(requester))
+    }
+
+    #[track_caller]
+    fn on_focus_changed<F: Fn(bool) + 'static>(self, callback: F) -> impl ModifierElement {
+        self.then(FocusObserverModifier(Rc::new(callback)))
+    }
+
+    #[track_caller]
+    fn on_key_event<F: Fn(&KeyEvent) -> bool + 'static>(self, handler: F) -> impl ModifierElement {
+        self.then(KeyEventModifier {
+            handler: Rc::new(handler),
+            preview: false,
+        })
+    }
+
+    #[track_caller]
+    fn on_preview_key_event<F: Fn(&KeyEvent) -> bool + 'static>(
+        self,
+        handler: F,
+    ) -> impl ModifierElement {
+        self.then(KeyEventModifier {
+            handler: Rc::new(handler),
+            preview: true,
+        })
+    }
+}
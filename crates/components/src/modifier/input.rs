@@ -0,0 +1,116 @@
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use rumpose_core::prelude::*;
+
+use super::ModifierElement;
+use crate::PointerInputScope;
+
+#[derive(Clone)]
+pub struct PointerEventModifier(PointerHandler);
+
+impl Debug for PointerEventModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PointerEventModifier")
+            .field(&Rc::as_ptr(&self.0))
+            .finish()
+    }
+}
+
+impl ModifierElement for PointerEventModifier {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        let handler = self.0.clone();
+
+        pointer_input(
+            scope,
+            move |event, pass| handler(event, pass),
+            move |scope| content(scope),
+        );
+    }
+}
+
+#[derive(Clone)]
+pub struct PointerInputModifier<K> {
+    key: K,
+    block: Rc<dyn Fn(&mut PointerInputScope)>,
+}
+
+impl<K> Debug for PointerInputModifier<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PointerInputModifier")
+            .field("block", &Rc::as_ptr(&self.block))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K: PartialEq + Clone + 'static> ModifierElement for PointerInputModifier<K> {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        let cache = scope
+            .use_state(|| Rc::new(RefCell::new(None::<(K, Rc<PointerInputScope>)>)))
+            .with(Clone::clone);
+        let gestures = {
+            let mut cache = cache.borrow_mut();
+
+            match &*cache {
+                Some((key, gestures)) if *key == self.key => gestures.clone(),
+                _ => {
+                    let mut gestures = PointerInputScope::new();
+
+                    (self.block)(&mut gestures);
+
+                    let gestures = Rc::new(gestures);
+
+                    *cache = Some((self.key.clone(), gestures.clone()));
+
+                    gestures
+                }
+            }
+        };
+
+        pointer_input(
+            scope,
+            move |event, pass| gestures.dispatch(event, pass),
+            move |scope| content(scope),
+        );
+    }
+}
+
+pub trait InputModifierExt {
+    /// Sets up gesture detectors for the pointer events hitting the modified
+    /// content. `block` runs again, resetting the detectors, only when `key`
+    /// changes, so the callbacks should read changing values from states.
+    fn pointer_input<K: PartialEq + Clone + 'static, F: Fn(&mut PointerInputScope) + 'static>(
+        self,
+        key: K,
+        block: F,
+    ) -> impl ModifierElement;
+    /// Passes the pointer events hitting the modified content to `handler`,
+    /// once per [`PointerEventPass`], with positions relative to the content.
+    fn on_pointer_event<F: Fn(&PointerEvent, PointerEventPass) + 'static>(
+        self,
+        handler: F,
+    ) -> impl ModifierElement;
+}
+
+impl<T: ModifierElement> InputModifierExt for T {
+    #[track_caller]
+    fn pointer_input<K: PartialEq + Clone + 'static, F: Fn(&mut PointerInputScope) + 'static>(
+        self,
+        key: K,
+        block: F,
+    ) -> impl ModifierElement {
+        self.then(PointerInputModifier {
+            key,
+            block: Rc::new(block),
+        })
+    }
+
+    #[track_caller]
+    fn on_pointer_event<F: Fn(&PointerEvent, PointerEventPass) + 'static>(
+        self,
+        handler: F,
+    ) -> impl ModifierElement {
+        self.then(PointerEventModifier(Rc::new(handler)))
+    }
+}
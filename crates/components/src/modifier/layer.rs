@@ -0,0 +1,50 @@
+use std::{fmt::Debug, rc::Rc};
+
+use rumpose_core::prelude::*;
+
+use super::ModifierElement;
+
+#[derive(Clone)]
+pub struct LayerModifier(LayerProps);
+
+impl Debug for LayerModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LayerModifier").finish_non_exhaustive()
+    }
+}
+
+impl ModifierElement for LayerModifier {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        layer(scope, self.0.clone(), move |scope| content(scope));
+    }
+}
+
+pub trait LayerModifierExt {
+    fn graphics_layer(self, props: LayerProps) -> impl ModifierElement;
+    fn z_index(self, value: f32) -> impl ModifierElement;
+    fn scale(self, value: f32) -> impl ModifierElement;
+    fn rotate(self, degrees: f32) -> impl ModifierElement;
+}
+
+impl<T: ModifierElement> LayerModifierExt for T {
+    #[track_caller]
+    fn graphics_layer(self, props: LayerProps) -> impl ModifierElement {
+        self.then(LayerModifier(props))
+    }
+
+    #[track_caller]
+    fn z_index(self, value: f32) -> impl ModifierElement {
+        self.graphics_layer(LayerProps::new().z_index(value))
+    }
+
+    #[track_caller]
+    fn scale(self, value: f32) -> impl ModifierElement {
+        self.graphics_layer(LayerProps::new().scale(value, value))
+    }
+
+    #[track_caller]
+    fn rotate(self, degrees: f32) -> impl ModifierElement {
+        self.graphics_layer(LayerProps::new().rotation(degrees))
+    }
+}
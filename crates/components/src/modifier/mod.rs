@@ -2,12 +2,21 @@ use std::{any::Any, fmt::Debug, rc::Rc};
 
 use rumpose_core::prelude::*;
 
+mod clickable;
 mod combined;
 mod content;
 mod draw;
+mod focus;
+mod input;
+mod layer;
+mod nested_scroll;
 mod padding;
+mod scroll;
 
-pub use self::{combined::CombinedModifier, content::ContentModifierExt, draw::*, padding::*};
+pub use self::{
+    clickable::*, combined::CombinedModifier, content::ContentModifierExt, draw::*, focus::*,
+    input::*, layer::*, nested_scroll::*, padding::*, scroll::*,
+};
 
 pub trait ModifierElement: Any + Debug {
     #[track_caller]
@@ -19,6 +28,13 @@ pub trait ModifierElement: Any + Debug {
     }
 
     fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>);
+
+    /// Adds the focus requesters and observers of the modifier to `props`,
+    /// which the next [`focusable`](FocusModifierExt::focusable) or
+    /// [`clickable`](ClickableModifierExt::clickable) in the chain takes.
+    fn focus_props(&self, props: FocusProps) -> FocusProps {
+        props
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,61 @@
+use std::{fmt::Debug, rc::Rc};
+
+use rumpose_core::prelude::*;
+
+use super::ModifierElement;
+use crate::{NestedScrollConnection, NestedScrollDispatcher, nested_scroll};
+
+#[derive(Clone)]
+pub struct NestedScrollModifier {
+    connection: Rc<dyn NestedScrollConnection>,
+    dispatcher: NestedScrollDispatcher,
+}
+
+impl Debug for NestedScrollModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("NestedScrollModifier")
+            .field(&Rc::as_ptr(&self.connection))
+            .finish()
+    }
+}
+
+impl ModifierElement for NestedScrollModifier {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        let Self {
+            connection,
+            dispatcher,
+        } = self.clone();
+
+        pointer_input(
+            scope,
+            move |event, pass| nested_scroll::on_pointer(event, pass, &connection, &dispatcher),
+            move |scope| content(scope),
+        );
+    }
+}
+
+pub trait NestedScrollModifierExt {
+    /// Lets `connection` take part in the scrolling of the modified content,
+    /// and binds `dispatcher` to the nested scroll nodes above it, so the
+    /// content can send its own scrolling to them.
+    fn nested_scroll<C: NestedScrollConnection + 'static>(
+        self,
+        connection: C,
+        dispatcher: NestedScrollDispatcher,
+    ) -> impl ModifierElement;
+}
+
+impl<T: ModifierElement> NestedScrollModifierExt for T {
+    #[track_caller]
+    fn nested_scroll<C: NestedScrollConnection + 'static>(
+        self,
+        connection: C,
+        dispatcher: NestedScrollDispatcher,
+    ) -> impl ModifierElement {
+        self.then(NestedScrollModifier {
+            connection: Rc::new(connection),
+            dispatcher,
+        })
+    }
+}
@@ -74,12 +74,52 @@ impl ModifierElement for FillModifier {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct SizeModifier {
+    width: Option<Dp>,
+    height: Option<Dp>,
+}
+
+impl ModifierElement for SizeModifier {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        let &Self { width, height } = self;
+
+        layout(
+            scope,
+            move |node, context, constraints| {
+                let density = context.density();
+
+                let [min_width, max_width] =
+                    width.map_or([constraints.min.width, constraints.max.width], |value| {
+                        let width = constraints.apply_width(value.to_px(density));
+
+                        [width, width]
+                    });
+
+                let [min_height, max_height] =
+                    height.map_or([constraints.min.height, constraints.max.height], |value| {
+                        let height = constraints.apply_height(value.to_px(density));
+
+                        [height, height]
+                    });
+
+                context.measure(
+                    node.children[0],
+                    Constraints::new(min_width, max_width, min_height, max_height),
+                )
+            },
+            move |scope| content(scope),
+        );
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PaddingModifier {
-    start: f32,
-    end: f32,
-    top: f32,
-    bottom: f32,
+    start: Dp,
+    end: Dp,
+    top: Dp,
+    bottom: Dp,
 }
 
 impl ModifierElement for PaddingModifier {
@@ -91,12 +131,16 @@ impl ModifierElement for PaddingModifier {
             top,
             bottom,
         } = self;
-        let horizontal = start + end;
-        let vertical = top + bottom;
 
         layout(
             scope,
             move |node, context, constraints| {
+                let density = context.density();
+                let [start, end, top, bottom] =
+                    [start, end, top, bottom].map(|value| value.to_px(density));
+                let horizontal = start + end;
+                let vertical = top + bottom;
+
                 let mut area =
                     context.measure(node.children[0], constraints.offset(-horizontal, -vertical));
 
@@ -118,13 +162,24 @@ pub trait LayoutModifierExt {
     fn fill_max_size(self) -> impl ModifierElement;
     fn fill_max_width(self) -> impl ModifierElement;
     fn fill_max_height(self) -> impl ModifierElement;
-    fn padding_all(self, value: f32) -> impl ModifierElement;
-    fn padding(self, start: f32, end: f32, top: f32, bottom: f32) -> impl ModifierElement;
+    fn size(self, width: impl Into<Dp>, height: impl Into<Dp>) -> impl ModifierElement;
+    fn width(self, value: impl Into<Dp>) -> impl ModifierElement;
+    fn height(self, value: impl Into<Dp>) -> impl ModifierElement;
+    fn padding_all(self, value: impl Into<Dp>) -> impl ModifierElement;
+    fn padding(
+        self,
+        start: impl Into<Dp>,
+        end: impl Into<Dp>,
+        top: impl Into<Dp>,
+        bottom: impl Into<Dp>,
+    ) -> impl ModifierElement;
 }
 
 impl<T: ModifierElement> LayoutModifierExt for T {
     #[track_caller]
-    fn padding_all(self, value: f32) -> impl ModifierElement {
+    fn padding_all(self, value: impl Into<Dp>) -> impl ModifierElement {
+        let value = value.into();
+
         self.then(PaddingModifier {
             start: value,
             end: value,
@@ -134,12 +189,42 @@ impl<T: ModifierElement> LayoutModifierExt for T {
     }
 
     #[track_caller]
-    fn padding(self, start: f32, end: f32, top: f32, bottom: f32) -> impl ModifierElement {
+    fn padding(
+        self,
+        start: impl Into<Dp>,
+        end: impl Into<Dp>,
+        top: impl Into<Dp>,
+        bottom: impl Into<Dp>,
+    ) -> impl ModifierElement {
         self.then(PaddingModifier {
-            start,
-            end,
-            top,
-            bottom,
+            start: start.into(),
+            end: end.into(),
+            top: top.into(),
+            bottom: bottom.into(),
+        })
+    }
+
+    #[track_caller]
+    fn size(self, width: impl Into<Dp>, height: impl Into<Dp>) -> impl ModifierElement {
+        self.then(SizeModifier {
+            width: Some(width.into()),
+            height: Some(height.into()),
+        })
+    }
+
+    #[track_caller]
+    fn width(self, value: impl Into<Dp>) -> impl ModifierElement {
+        self.then(SizeModifier {
+            width: Some(value.into()),
+            height: None,
+        })
+    }
+
+    #[track_caller]
+    fn height(self, value: impl Into<Dp>) -> impl ModifierElement {
+        self.then(SizeModifier {
+            width: None,
+            height: Some(value.into()),
         })
     }
 
@@ -0,0 +1,274 @@
+use std::rc::Rc;
+
+use rumpose_core::prelude::*;
+
+use super::{
+    InputModifierExt, LayerModifierExt, Modifier, ModifierElement, NestedScrollModifierExt,
+};
+use crate::{
+    DragGestures, NestedScrollConnection, NestedScrollDispatcher, NestedScrollSource, Orientation,
+    PointerInputScope, ScrollState,
+};
+
+/// Returns the component of `offset` along the scrolled axis.
+const fn along(orientation: Orientation, offset: Point2D) -> f32 {
+    match orientation {
+        Orientation::Horizontal => offset.x,
+        Orientation::Vertical => offset.y,
+    }
+}
+
+/// Scrolling of a scroll modifier or a lazy list, taking part in nested
+/// scrolling.
+#[derive(Clone)]
+struct Scrollable {
+    state: ScrollState,
+    orientation: Orientation,
+    dispatcher: NestedScrollDispatcher,
+}
+
+impl Scrollable {
+    /// Returns `value` as an offset along the scrolled axis.
+    const fn offset(&self, value: f32) -> Point2D {
+        match self.orientation {
+            Orientation::Horizontal => Point2D::new(value, 0.),
+            Orientation::Vertical => Point2D::new(0., value),
+        }
+    }
+
+    /// Moves the content by `delta` without the nested scroll parents and
+    /// returns the consumed part.
+    fn scroll_self(&self, delta: Point2D) -> Point2D {
+        self.offset(-self.state.scroll_by(-along(self.orientation, delta)))
+    }
+
+    /// Moves the content by `delta` together with the nested scroll parents
+    /// and returns the consumed part.
+    fn scroll(&self, delta: Point2D, source: NestedScrollSource) -> Point2D {
+        let delta = self.offset(along(self.orientation, delta));
+        let pre = self.dispatcher.dispatch_pre_scroll(delta, source);
+        let consumed = self.scroll_self(delta - pre);
+        let post = self
+            .dispatcher
+            .dispatch_post_scroll(consumed, delta - pre - consumed, source);
+
+        pre + consumed + post
+    }
+
+    fn fling(&self, velocity: Point2D) {
+        let velocity = self.offset(along(self.orientation, velocity));
+        let available = velocity - self.dispatcher.dispatch_pre_fling(velocity);
+        let (scrollable, end) = (self.clone(), self.clone());
+
+        // The state flings its value, which moves opposite to the content.
+        self.state.decay(
+            -along(self.orientation, available),
+            move |_, delta| {
+                let consumed =
+                    scrollable.scroll(scrollable.offset(-delta), NestedScrollSource::Fling);
+
+                -along(scrollable.orientation, consumed)
+            },
+            move |remaining| {
+                let remaining = end.offset(-remaining);
+
+                end.dispatcher
+                    .dispatch_post_fling(available - remaining, remaining);
+            },
+        );
+    }
+
+    fn on_wheel(&self, event: &PointerEvent, pass: PointerEventPass) {
+        if pass != PointerEventPass::Main
+            || event.kind != PointerEventType::Scroll
+            || event.is_consumed()
+        {
+            return;
+        }
+
+        self.state.stop_animation();
+
+        // Wheel deltas move the value, and the content the other way. Lets
+        // the parents scroll once nothing here can.
+        if self.scroll(-event.scroll_delta, NestedScrollSource::Wheel) != Point2D::default() {
+            event.consume();
+        }
+    }
+
+    fn detect_drag(&self, gestures: &mut PointerInputScope) {
+        let (start, drag, end) = (self.clone(), self.clone(), self.clone());
+
+        gestures.detect_drag_gestures(
+            DragGestures::new()
+                .orientation(self.orientation)
+                .on_drag_start(move |_| start.state.stop_animation())
+                .on_drag(move |_, delta| {
+                    drag.scroll(delta, NestedScrollSource::Drag);
+                })
+                .on_drag_end(move |velocity| end.fling(velocity)),
+        );
+    }
+}
+
+impl NestedScrollConnection for Scrollable {
+    /// Scrolls by what the descendants left, e.g. once an inner list reached
+    /// its end.
+    fn on_post_scroll(
+        &self,
+        _consumed: Point2D,
+        available: Point2D,
+        _source: NestedScrollSource,
+    ) -> Point2D {
+        self.scroll_self(available)
+    }
+
+    fn on_post_fling(&self, _consumed: Point2D, available: Point2D) -> Point2D {
+        let velocity = along(self.orientation, available);
+
+        self.state.fling(-velocity);
+
+        self.offset(velocity)
+    }
+}
+
+/// Clipping and input of a scrolling component, without its layout.
+#[derive(Clone)]
+pub(crate) struct ScrollableModifier {
+    state: ScrollState,
+    orientation: Orientation,
+}
+
+impl ScrollableModifier {
+    pub(crate) const fn new(state: ScrollState, orientation: Orientation) -> Self {
+        Self { state, orientation }
+    }
+}
+
+impl std::fmt::Debug for ScrollableModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScrollableModifier")
+            .field("value", &self.state.value())
+            .field("orientation", &self.orientation)
+            .finish()
+    }
+}
+
+impl ModifierElement for ScrollableModifier {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        let scrollable = Scrollable {
+            state: self.state.clone(),
+            orientation: self.orientation,
+            dispatcher: scope
+                .use_state(NestedScrollDispatcher::new)
+                .with(Clone::clone),
+        };
+        let (wheel, drag) = (scrollable.clone(), scrollable.clone());
+
+        Modifier
+            .graphics_layer(LayerProps::new().clip(|area| Path::rect(Rect::from(area), None)))
+            .nested_scroll(scrollable.clone(), scrollable.dispatcher)
+            .on_pointer_event(move |event, pass| wheel.on_wheel(event, pass))
+            .pointer_input((self.state.clone(), self.orientation), move |gestures| {
+                drag.detect_drag(gestures);
+            })
+            .apply(scope, content);
+    }
+}
+
+#[derive(Clone)]
+pub struct ScrollModifier {
+    state: ScrollState,
+    orientation: Orientation,
+}
+
+impl std::fmt::Debug for ScrollModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScrollModifier")
+            .field("value", &self.state.value())
+            .field("orientation", &self.orientation)
+            .finish()
+    }
+}
+
+impl ModifierElement for ScrollModifier {
+    #[track_caller]
+    fn apply(&self, scope: Scope, content: Rc<dyn Fn(Scope) + 'static>) {
+        let Self { state, orientation } = self.clone();
+        // Read while composing, so the content is placed again on every
+        // scroll.
+        let value = state.value();
+
+        ScrollableModifier::new(state.clone(), orientation).apply(
+            scope,
+            Rc::new(move |scope| {
+                let state = state.clone();
+                let content = content.clone();
+
+                layout(
+                    scope,
+                    move |node, context, constraints| {
+                        let child = node.children[0];
+                        let content_size = context.measure(child, match orientation {
+                            Orientation::Horizontal => Constraints::new(
+                                0.,
+                                f32::INFINITY,
+                                constraints.min.height,
+                                constraints.max.height,
+                            ),
+                            Orientation::Vertical => Constraints::new(
+                                constraints.min.width,
+                                constraints.max.width,
+                                0.,
+                                f32::INFINITY,
+                            ),
+                        });
+                        let size = constraints.apply(content_size);
+
+                        state.set_max_value(along(orientation, Point2D::from(content_size - size)));
+
+                        let value = value.min(state.max_value());
+                        let (x, y) = match orientation {
+                            Orientation::Horizontal => (-value, 0.),
+                            Orientation::Vertical => (0., -value),
+                        };
+
+                        context.place(child, x, y);
+
+                        size
+                    },
+                    move |scope| content(scope),
+                );
+            }),
+        );
+    }
+}
+
+pub trait ScrollModifierExt {
+    /// Measures the content with unbounded height, clips it to the available
+    /// height and moves it by the value of `state`, scrolling on drags and
+    /// wheel events.
+    fn vertical_scroll(self, state: ScrollState) -> impl ModifierElement;
+    /// Measures the content with unbounded width, clips it to the available
+    /// width and moves it by the value of `state`, scrolling on drags and
+    /// wheel events.
+    fn horizontal_scroll(self, state: ScrollState) -> impl ModifierElement;
+}
+
+impl<T: ModifierElement> ScrollModifierExt for T {
+    #[track_caller]
+    fn vertical_scroll(self, state: ScrollState) -> impl ModifierElement {
+        self.then(ScrollModifier {
+            state,
+            orientation: Orientation::Vertical,
+        })
+    }
+
+    #[track_caller]
+    fn horizontal_scroll(self, state: ScrollState) -> impl ModifierElement {
+        self.then(ScrollModifier {
+            state,
+            orientation: Orientation::Horizontal,
+        })
+    }
+}
@@ -0,0 +1,151 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rumpose_core::prelude::*;
+
+/// What moves the content of a nested scroll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NestedScrollSource {
+    Drag,
+    Wheel,
+    Fling,
+}
+
+/// Takes part in the scrolling of the descendants of a
+/// [`nested_scroll`](crate::NestedScrollModifierExt::nested_scroll)
+/// modifier. Deltas are movements of the content in pixels, in the direction
+/// of the pointer, and velocities are in pixels per second. Every method
+/// returns the part of `available` it consumed.
+pub trait NestedScrollConnection {
+    /// Called before a descendant scrolls, which only scrolls by what is left.
+    fn on_pre_scroll(&self, _available: Point2D, _source: NestedScrollSource) -> Point2D {
+        Point2D::default()
+    }
+
+    /// Called after a descendant scrolled by `consumed`, with the rest it
+    /// could not scroll by.
+    fn on_post_scroll(
+        &self,
+        _consumed: Point2D,
+        _available: Point2D,
+        _source: NestedScrollSource,
+    ) -> Point2D {
+        Point2D::default()
+    }
+
+    /// Called before a descendant flings, which only flings with what is left.
+    fn on_pre_fling(&self, _available: Point2D) -> Point2D {
+        Point2D::default()
+    }
+
+    /// Called after the fling of a descendant stopped, with the velocity it
+    /// had left when reaching a bound.
+    fn on_post_fling(&self, _consumed: Point2D, _available: Point2D) -> Point2D {
+        Point2D::default()
+    }
+}
+
+type Connections = Vec<Rc<dyn NestedScrollConnection>>;
+
+thread_local! {
+    /// Connections of the nested scroll nodes hit by the current press or
+    /// wheel event so far, outermost first, with the dispatch of that event.
+    static HIT: RefCell<(Option<u64>, Connections)> = RefCell::default();
+}
+
+/// Sends the scrolling of a component to the connections of the
+/// [`nested_scroll`](crate::NestedScrollModifierExt::nested_scroll) nodes
+/// above it. Those are found on every press and wheel event, among the nodes
+/// the event hits.
+#[derive(Clone, Default)]
+pub struct NestedScrollDispatcher {
+    /// Outermost first.
+    parents: Rc<RefCell<Connections>>,
+}
+
+impl NestedScrollDispatcher {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Passes `available` to the parents, outermost first, and returns what
+    /// they consumed.
+    pub fn dispatch_pre_scroll(&self, available: Point2D, source: NestedScrollSource) -> Point2D {
+        self.parents()
+            .iter()
+            .fold(Point2D::default(), |consumed, parent| {
+                consumed + parent.on_pre_scroll(available - consumed, source)
+            })
+    }
+
+    /// Passes what is left after scrolling to the parents, innermost first,
+    /// and returns what they consumed.
+    pub fn dispatch_post_scroll(
+        &self,
+        consumed: Point2D,
+        available: Point2D,
+        source: NestedScrollSource,
+    ) -> Point2D {
+        self.parents()
+            .iter()
+            .rev()
+            .fold(Point2D::default(), |taken, parent| {
+                taken + parent.on_post_scroll(consumed + taken, available - taken, source)
+            })
+    }
+
+    pub fn dispatch_pre_fling(&self, available: Point2D) -> Point2D {
+        self.parents()
+            .iter()
+            .fold(Point2D::default(), |consumed, parent| {
+                consumed + parent.on_pre_fling(available - consumed)
+            })
+    }
+
+    pub fn dispatch_post_fling(&self, consumed: Point2D, available: Point2D) -> Point2D {
+        self.parents()
+            .iter()
+            .rev()
+            .fold(Point2D::default(), |taken, parent| {
+                taken + parent.on_post_fling(consumed + taken, available - taken)
+            })
+    }
+
+    /// Returns the parents without borrowing them, as connections may scroll
+    /// other nested scroll nodes.
+    fn parents(&self) -> Connections {
+        self.parents.borrow().clone()
+    }
+}
+
+/// Called by a nested scroll node in the initial pass, which reaches the
+/// nodes hit by an event from the outermost. Binds `dispatcher` to the
+/// connections above the node, and adds `connection` for the nodes below.
+pub(crate) fn on_pointer(
+    event: &PointerEvent,
+    pass: PointerEventPass,
+    connection: &Rc<dyn NestedScrollConnection>,
+    dispatcher: &NestedScrollDispatcher,
+) {
+    if pass != PointerEventPass::Initial
+        || !matches!(
+            event.kind,
+            PointerEventType::Press | PointerEventType::Scroll
+        )
+    {
+        return;
+    }
+
+    HIT.with_borrow_mut(|(hit, connections)| {
+        let current = Some(event.dispatch);
+
+        // The first node hit by a new dispatch.
+        if *hit != current {
+            *hit = current;
+            connections.clear();
+        }
+
+        dispatcher.parents.replace(connections.clone());
+        connections.push(connection.clone());
+    });
+}
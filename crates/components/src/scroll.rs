@@ -0,0 +1,191 @@
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use rumpose_core::prelude::*;
+
+/// Rate at which the velocity of a fling decays, per second.
+const FLING_FRICTION: f32 = 4.2;
+/// Velocity in pixels per second below which a fling stops.
+const FLING_STOP_VELOCITY: f32 = 10.;
+const ANIMATION_DURATION: Duration = Duration::from_millis(300);
+
+type AnimationStep = Rc<dyn Fn(&ScrollState, Duration) -> bool>;
+
+/// Scroll position of a
+/// [`vertical_scroll`](crate::ScrollModifierExt::vertical_scroll) or
+/// [`horizontal_scroll`](crate::ScrollModifierExt::horizontal_scroll)
+/// modifier, in pixels from the start of the content.
+#[derive(Clone)]
+pub struct ScrollState {
+    value: State<f32>,
+    /// Written while measuring, so it is not a [`State`], which would compose
+    /// the content again.
+    max_value: Rc<Cell<f32>>,
+    /// Incremented to stop the running animation.
+    animation: Rc<Cell<u64>>,
+}
+
+impl PartialEq for ScrollState {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.animation, &other.animation)
+    }
+}
+
+impl ScrollState {
+    #[track_caller]
+    #[must_use]
+    pub fn new(scope: Scope) -> Self {
+        Self::with_initial(scope, 0.)
+    }
+
+    #[track_caller]
+    #[must_use]
+    pub fn with_initial(scope: Scope, value: f32) -> Self {
+        Self {
+            value: scope.use_state(|| value.max(0.)),
+            max_value: scope
+                .use_state(|| Rc::new(Cell::new(f32::INFINITY)))
+                .with(Clone::clone),
+            animation: scope.use_state(Rc::default).with(Clone::clone),
+        }
+    }
+
+    /// Returns the value, clamped to the
+    /// [`max_value`](ScrollState::max_value) of the last measure.
+    #[must_use]
+    pub fn value(&self) -> f32 {
+        self.value.with(Clone::clone).min(self.max_value())
+    }
+
+    /// Returns the largest value, where the end of the content reaches the
+    /// end of the viewport. Infinite until the content is measured, and not
+    /// observed by the composition.
+    #[must_use]
+    pub fn max_value(&self) -> f32 {
+        self.max_value.get()
+    }
+
+    /// Returns `true` while an animation or fling moves the content.
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        self.animation.get() % 2 == 1
+    }
+
+    /// Jumps to `value`, stopping any animation.
+    pub fn scroll_to(&self, value: f32) {
+        self.stop_animation();
+        self.set_value(value);
+    }
+
+    /// Moves the content by `delta` and returns the part of it that was
+    /// consumed before reaching a bound.
+    pub fn scroll_by(&self, delta: f32) -> f32 {
+        let value = self.value();
+
+        self.set_value(value + delta) - value
+    }
+
+    /// Moves the content to `value` over a short animation, advanced by
+    /// [`ComposerExt::advance_frame`].
+    pub fn animate_scroll_to(&self, value: f32) {
+        let from = self.value();
+        let to = value.clamp(0., self.max_value());
+
+        self.animate(Rc::new(move |state, elapsed| {
+            let fraction = (elapsed.as_secs_f32() / ANIMATION_DURATION.as_secs_f32()).min(1.);
+            // Fast out, slow in.
+            let eased = 1. - (1. - fraction).powi(3);
+
+            state.set_value((to - from).mul_add(eased, from));
+
+            fraction < 1.
+        }));
+    }
+
+    /// Keeps moving the content with `velocity`, in pixels per second,
+    /// slowing down until it stops or reaches a bound.
+    pub fn fling(&self, velocity: f32) {
+        self.decay(velocity, Self::scroll_by, |_| {});
+    }
+
+    /// Flings, moving the content with `scroll_by` on every frame, and calls
+    /// `on_end` with the velocity left when it does not consume a whole
+    /// step, or zero when the fling stops by itself.
+    pub(crate) fn decay<S: Fn(&Self, f32) -> f32 + 'static, E: Fn(f32) + 'static>(
+        &self,
+        velocity: f32,
+        scroll_by: S,
+        on_end: E,
+    ) {
+        let last = Cell::new(0.);
+
+        self.animate(Rc::new(move |state, elapsed| {
+            let decay = (-FLING_FRICTION * elapsed.as_secs_f32()).exp();
+            let offset = velocity / FLING_FRICTION * (1. - decay);
+            let delta = offset - last.replace(offset);
+            let blocked = (delta - scroll_by(state, delta)).abs() >= 0.5;
+            let remaining = velocity * decay;
+
+            if !blocked && remaining.abs() > FLING_STOP_VELOCITY {
+                return true;
+            }
+
+            on_end(if blocked { remaining } else { 0. });
+
+            false
+        }));
+    }
+
+    pub fn stop_animation(&self) {
+        if self.is_animating() {
+            self.animation.set(self.animation.get() + 1);
+        }
+    }
+
+    /// Sets the largest value from the measured content. The layout clamps
+    /// the value it places the content at, so content that now ends before
+    /// the viewport is moved back without composing it again.
+    pub(crate) fn set_max_value(&self, value: f32) {
+        self.max_value.set(value.max(0.));
+    }
+
+    /// Sets the value clamped to the bounds and returns it.
+    fn set_value(&self, value: f32) -> f32 {
+        let value = value.clamp(0., self.max_value());
+
+        if self.value.with(Clone::clone) != value {
+            self.value.set(value);
+        }
+
+        value
+    }
+
+    /// Runs `step` on every frame with the time since the first one, until it
+    /// returns `false` or another animation starts. Odd generations are
+    /// running animations.
+    fn animate(&self, step: AnimationStep) {
+        self.stop_animation();
+
+        let generation = self.animation.get() + 1;
+
+        self.animation.set(generation);
+        self.schedule(generation, None, step);
+    }
+
+    fn schedule(&self, generation: u64, start: Option<Duration>, step: AnimationStep) {
+        let state = self.clone();
+
+        request_frame(move |time| {
+            if state.animation.get() != generation {
+                return;
+            }
+
+            let start = start.unwrap_or(time);
+
+            if step(&state, time.saturating_sub(start)) {
+                state.schedule(generation, Some(start), step);
+            } else {
+                state.animation.set(generation + 1);
+            }
+        });
+    }
+}
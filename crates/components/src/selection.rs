@@ -0,0 +1,433 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::{Rc, Weak},
+};
+
+use rumpose_core::prelude::*;
+
+use crate::{AnnotatedString, Clipboard, TextLayoutResult};
+
+/// Layout of a [`text`](crate::text), kept with the constraints and density
+/// it was computed for.
+pub(crate) type TextLayoutCache = Rc<RefCell<Option<(Constraints, Density, TextLayoutResult)>>>;
+
+/// Text registered with the enclosing [`selection_container`] while it is
+/// drawn.
+pub(crate) struct Selectable {
+    pub(crate) content: AnnotatedString,
+    pub(crate) layout: TextLayoutCache,
+    pub(crate) area: Cell<Rect2D>,
+}
+
+impl Selectable {
+    fn offset_for_position(&self, position: Point2D) -> usize {
+        let area = self.area.get();
+
+        self.layout.borrow().as_ref().map_or(0, |(_, _, layout)| {
+            layout.offset_for_position(Point2D::new(
+                position.x - area.origin.x,
+                position.y - area.origin.y,
+            ))
+        })
+    }
+}
+
+type Selectables = Rc<RefCell<Vec<Weak<Selectable>>>>;
+
+thread_local! {
+    /// Selection containers being drawn, innermost last.
+    static CONTAINERS: RefCell<Vec<Selectables>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Registers `selectable` with the innermost selection container being
+/// drawn, if any.
+pub(crate) fn register(selectable: &Rc<Selectable>) {
+    CONTAINERS.with_borrow(|containers| {
+        if let Some(selectables) = containers.last() {
+            let mut selectables = selectables.borrow_mut();
+
+            selectables.retain(|item| item.strong_count() > 0);
+
+            if !selectables
+                .iter()
+                .any(|item| item.as_ptr() == Rc::as_ptr(selectable))
+            {
+                selectables.push(Rc::downgrade(selectable));
+            }
+        }
+    });
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum Selection {
+    #[default]
+    None,
+    All,
+    /// Positions the selection was dragged between, in the coordinates of the
+    /// tree. Kept as positions so the selection survives recomposition of the
+    /// texts.
+    Range {
+        start: Point2D,
+        end: Point2D,
+    },
+}
+
+/// Selection of a [`selection_container`], shared with the code that reads
+/// or changes it.
+#[derive(Clone)]
+pub struct SelectionState {
+    selection: State<Selection>,
+    selectables: State<Selectables>,
+}
+
+impl SelectionState {
+    #[track_caller]
+    #[must_use]
+    pub fn new(scope: Scope) -> Self {
+        Self {
+            selection: scope.use_state(Selection::default),
+            selectables: scope.use_state(Selectables::default),
+        }
+    }
+
+    #[must_use]
+    pub fn has_selection(&self) -> bool {
+        self.ranges().iter().any(|(_, start, end)| start < end)
+    }
+
+    /// Starts selecting at `position`, e.g. where a drag starts.
+    pub fn start_selection(&self, position: Point2D) {
+        self.selection.set(Selection::Range {
+            start: position,
+            end: position,
+        });
+    }
+
+    /// Moves the end of the selection started with
+    /// [`start_selection`](SelectionState::start_selection) to `position`.
+    pub fn update_selection(&self, position: Point2D) {
+        if let Selection::Range { start, .. } = self.selection.with(Clone::clone) {
+            self.selection.set(Selection::Range {
+                start,
+                end: position,
+            });
+        }
+    }
+
+    pub fn select_all(&self) {
+        self.selection.set(Selection::All);
+    }
+
+    pub fn clear(&self) {
+        self.selection.set(Selection::None);
+    }
+
+    /// Returns the selected text, with the parts of different texts on
+    /// separate lines.
+    #[must_use]
+    pub fn selected_text(&self) -> String {
+        self.ranges()
+            .iter()
+            .filter(|(_, start, end)| start < end)
+            .map(|(selectable, start, end)| {
+                selectable.content.slice(*start, *end).text().to_owned()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Copies the selected text to `clipboard`. Returns `false` if nothing is
+    /// selected.
+    pub fn copy(&self, clipboard: &dyn Clipboard) -> bool {
+        let text = self.selected_text();
+
+        if text.is_empty() {
+            return false;
+        }
+
+        clipboard.set_text(&text);
+
+        true
+    }
+
+    /// Returns the registered texts in reading order.
+    fn selectables(&self) -> Vec<Rc<Selectable>> {
+        let mut selectables = self
+            .selectables
+            .with(Clone::clone)
+            .borrow()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect::<Vec<_>>();
+
+        selectables.sort_by(|a, b| {
+            let (a, b) = (a.area.get().origin, b.area.get().origin);
+
+            a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x))
+        });
+
+        selectables
+    }
+
+    /// Returns the text and character offset closest to `position`.
+    fn position(selectables: &[Rc<Selectable>], position: Point2D) -> (usize, usize) {
+        for (index, selectable) in selectables.iter().enumerate() {
+            let area = selectable.area.get();
+
+            if position.y < area.min_y() || (position.y < area.max_y() && position.x < area.min_x())
+            {
+                return (index, 0);
+            }
+
+            if position.y < area.max_y() && position.x <= area.max_x() {
+                return (index, selectable.offset_for_position(position));
+            }
+        }
+
+        selectables.last().map_or((0, 0), |selectable| {
+            (selectables.len() - 1, selectable.content.len())
+        })
+    }
+
+    /// Returns the selected character range of every text.
+    fn ranges(&self) -> Vec<(Rc<Selectable>, usize, usize)> {
+        let selectables = self.selectables();
+
+        let (start, end) = match self.selection.with(Clone::clone) {
+            Selection::None => return Vec::new(),
+            Selection::All => (
+                (0, 0),
+                (
+                    selectables.len().saturating_sub(1),
+                    selectables.last().map_or(0, |item| item.content.len()),
+                ),
+            ),
+            Selection::Range { start, end } => {
+                let start = Self::position(&selectables, start);
+                let end = Self::position(&selectables, end);
+
+                (start.min(end), start.max(end))
+            }
+        };
+
+        selectables
+            .into_iter()
+            .enumerate()
+            .skip(start.0)
+            .take(end.0 + 1 - start.0)
+            .map(|(index, selectable)| {
+                let from = if index == start.0 { start.1 } else { 0 };
+                let to = if index == end.0 {
+                    end.1
+                } else {
+                    selectable.content.len()
+                };
+
+                (selectable, from, to)
+            })
+            .collect()
+    }
+
+    fn selection_rects(&self) -> Vec<Rect2D> {
+        self.ranges()
+            .iter()
+            .flat_map(|(selectable, start, end)| {
+                let area = selectable.area.get();
+
+                selectable
+                    .layout
+                    .borrow()
+                    .as_ref()
+                    .map(|(_, _, layout)| layout.selection_rects(*start, *end))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(move |rect| {
+                        Rect2D::new(
+                            Point2D::new(
+                                area.origin.x + rect.origin.x,
+                                area.origin.y + rect.origin.y,
+                            ),
+                            rect.size,
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Selects by dragging the primary button, unless a descendant consumed
+    /// the events.
+    fn on_pointer(&self, event: &PointerEvent, pass: PointerEventPass, origin: Point2D) {
+        if pass != PointerEventPass::Main
+            || event.is_consumed()
+            || event.button != PointerButton::Primary
+        {
+            return;
+        }
+
+        let position = origin + event.position;
+
+        match event.kind {
+            PointerEventType::Press => self.start_selection(position),
+            PointerEventType::Move if event.pressed => self.update_selection(position),
+            _ => return,
+        }
+
+        event.consume();
+    }
+
+    fn on_key(&self, event: &KeyEvent, clipboard: Option<&dyn Clipboard>) -> bool {
+        if event.kind != KeyEventType::KeyDown {
+            return false;
+        }
+
+        match event.key {
+            Key::Character(char) if event.modifiers.is_shortcut() => {
+                match char.to_ascii_lowercase() {
+                    'a' => {
+                        self.select_all();
+
+                        true
+                    }
+                    'c' => clipboard.is_some_and(|clipboard| self.copy(clipboard)),
+                    _ => false,
+                }
+            }
+            Key::Escape if self.has_selection() => {
+                self.clear();
+
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SelectionContainerProps {
+    selection_color: Color,
+    clipboard: Option<Rc<dyn Clipboard>>,
+}
+
+impl Default for SelectionContainerProps {
+    fn default() -> Self {
+        Self {
+            selection_color: Color::from_argb(0x66, 0x33, 0x66, 0xFF),
+            clipboard: None,
+        }
+    }
+}
+
+impl SelectionContainerProps {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn selection_color(mut self, color: Color) -> Self {
+        self.selection_color = color;
+
+        self
+    }
+
+    /// Clipboard the copy shortcut writes to.
+    #[must_use]
+    pub fn clipboard(mut self, value: Rc<dyn Clipboard>) -> Self {
+        self.clipboard = Some(value);
+
+        self
+    }
+}
+
+/// Makes the [`text`](crate::text) descendants of `content` selectable
+/// together by dragging over them, drawing the selection of `state` over
+/// them. The container takes the focus when pressed, handling the select all
+/// and copy shortcuts while focused.
+#[track_caller]
+pub fn selection_container<C: Fn(Scope) + Clone + 'static>(
+    scope: Scope,
+    state: SelectionState,
+    props: SelectionContainerProps,
+    content: C,
+) {
+    // Read while composing, so the container is drawn again when the
+    // selection changes.
+    let selection = state.selection.with(Clone::clone);
+    let key_state = state.clone();
+    let clipboard = props.clipboard.clone();
+    // Pointer positions are relative to the container, selectable areas are
+    // in the coordinates of the tree.
+    let origin = Rc::new(Cell::new(Point2D::default()));
+    let requester = FocusRequester::new(scope);
+    let focus_props = FocusProps::new().requester(requester.clone());
+    let key_input_content = move |scope: Scope| {
+        let state = state.clone();
+        let content = content.clone();
+        let selection_color = props.selection_color;
+        let origin = origin.clone();
+        let pointer_state = state.clone();
+        let pointer_origin = origin.clone();
+        let requester = requester.clone();
+
+        pointer_input(
+            scope,
+            move |event, pass| {
+                if pass == PointerEventPass::Main
+                    && event.kind == PointerEventType::Press
+                    && !event.is_consumed()
+                {
+                    requester.request_focus();
+                }
+
+                pointer_state.on_pointer(event, pass, pointer_origin.get());
+            },
+            move |scope| {
+                let state = state.clone();
+                let content = content.clone();
+                let origin = origin.clone();
+
+                draw(
+                    scope,
+                    move |context| {
+                        origin.set(context.area().origin);
+                        context.set_do_content_draw(false);
+
+                        CONTAINERS.with_borrow_mut(|containers| {
+                            containers.push(state.selectables.with(Clone::clone));
+                        });
+
+                        context.draw_content();
+
+                        CONTAINERS.with_borrow_mut(Vec::pop);
+
+                        if selection == Selection::None {
+                            return;
+                        }
+
+                        context.with_canvas(|canvas| {
+                            let mut paint = Paint::default();
+
+                            paint.set_color4f(selection_color.to_engine4f(), None);
+
+                            for rect in state.selection_rects() {
+                                canvas.draw_rect(Rect::from(rect), &paint);
+                            }
+                        });
+                    },
+                    move |scope| content(scope),
+                );
+            },
+        );
+    };
+
+    focus_target(scope, focus_props, move |scope| {
+        let key_state = key_state.clone();
+        let clipboard = clipboard.clone();
+
+        key_input(
+            scope,
+            move |event| key_state.on_key(event, clipboard.as_deref()),
+            key_input_content.clone(),
+        );
+    });
+}
@@ -0,0 +1,384 @@
+use std::rc::Rc;
+
+use crate::{SpanStyle, TextParagraphStyle};
+
+/// Item attached to the characters `start..end` of an [`AnnotatedString`].
+/// Offsets count characters, not bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnotatedRange<T> {
+    pub item: T,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl<T> AnnotatedRange<T> {
+    #[must_use]
+    pub const fn new(item: T, start: usize, end: usize) -> Self {
+        Self { item, start, end }
+    }
+
+    #[must_use]
+    pub const fn contains(&self, offset: usize) -> bool {
+        self.start <= offset && offset < self.end
+    }
+
+    #[must_use]
+    pub const fn intersects(&self, start: usize, end: usize) -> bool {
+        self.start < end && start < self.end
+    }
+}
+
+/// Tagged string value, e.g. a URL attached to a link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringAnnotation {
+    pub tag: Rc<str>,
+    pub value: Rc<str>,
+}
+
+/// Text with ranged span styles, paragraph styles and string annotations.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnnotatedString {
+    text: Rc<str>,
+    span_styles: Rc<[AnnotatedRange<SpanStyle>]>,
+    paragraph_styles: Rc<[AnnotatedRange<TextParagraphStyle>]>,
+    annotations: Rc<[AnnotatedRange<StringAnnotation>]>,
+}
+
+impl AnnotatedString {
+    #[must_use]
+    pub fn builder() -> AnnotatedStringBuilder {
+        AnnotatedStringBuilder::default()
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Number of characters in the text.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    #[must_use]
+    pub fn span_styles(&self) -> &[AnnotatedRange<SpanStyle>] {
+        &self.span_styles
+    }
+
+    #[must_use]
+    pub fn paragraph_styles(&self) -> &[AnnotatedRange<TextParagraphStyle>] {
+        &self.paragraph_styles
+    }
+
+    #[must_use]
+    pub fn annotations(&self) -> &[AnnotatedRange<StringAnnotation>] {
+        &self.annotations
+    }
+
+    /// Returns the annotations with the given tag that overlap the characters
+    /// `start..end`.
+    pub fn string_annotations<'a>(
+        &'a self,
+        tag: &'a str,
+        start: usize,
+        end: usize,
+    ) -> impl Iterator<Item = &'a AnnotatedRange<StringAnnotation>> {
+        self.annotations
+            .iter()
+            .filter(move |range| &*range.item.tag == tag && range.intersects(start, end))
+    }
+
+    /// Returns the annotations covering the character at `offset`.
+    pub fn annotations_at(
+        &self,
+        offset: usize,
+    ) -> impl Iterator<Item = &AnnotatedRange<StringAnnotation>> {
+        self.annotations
+            .iter()
+            .filter(move |range| range.contains(offset))
+    }
+
+    /// Returns the byte offset of the character at `offset`, or the text length
+    /// past the end.
+    pub(crate) fn byte_offset(&self, offset: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(offset)
+            .map_or(self.text.len(), |(index, _)| index)
+    }
+
+    /// Returns the text of the characters `start..end`.
+    #[must_use]
+    pub fn slice(&self, start: usize, end: usize) -> &str {
+        &self.text[self.byte_offset(start)..self.byte_offset(end)]
+    }
+
+    /// Splits the text into consecutive paragraphs, each with its optional
+    /// paragraph style. Overlapping paragraph styles are cut at the start of
+    /// the later one.
+    pub(crate) fn paragraphs(&self) -> Vec<(usize, usize, Option<&TextParagraphStyle>)> {
+        let len = self.len();
+        let mut styles = self.paragraph_styles.iter().collect::<Vec<_>>();
+        let mut result = Vec::new();
+        let mut offset = 0;
+
+        styles.sort_by_key(|range| range.start);
+
+        for range in styles {
+            let start = range.start.clamp(offset, len);
+            let end = range.end.min(len);
+
+            if start >= end {
+                continue;
+            }
+
+            if offset < start {
+                result.push((offset, start, None));
+            }
+
+            result.push((start, end, Some(&range.item)));
+            offset = end;
+        }
+
+        if offset < len || result.is_empty() {
+            result.push((offset, len, None));
+        }
+
+        result
+    }
+
+    /// Splits the characters `start..end` at every span style boundary, each
+    /// segment with the span styles covering it in the order they were added.
+    pub(crate) fn segments(
+        &self,
+        start: usize,
+        end: usize,
+    ) -> Vec<(usize, usize, Vec<&SpanStyle>)> {
+        let mut bounds = self
+            .span_styles
+            .iter()
+            .flat_map(|range| [range.start, range.end])
+            .filter(|offset| start < *offset && *offset < end)
+            .chain([start, end])
+            .collect::<Vec<_>>();
+
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        bounds
+            .windows(2)
+            .map(|window| {
+                let styles = self
+                    .span_styles
+                    .iter()
+                    .filter(|range| range.intersects(window[0], window[1]))
+                    .map(|range| &range.item)
+                    .collect();
+
+                (window[0], window[1], styles)
+            })
+            .collect()
+    }
+}
+
+impl From<&str> for AnnotatedString {
+    fn from(value: &str) -> Self {
+        Self {
+            text: value.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<String> for AnnotatedString {
+    fn from(value: String) -> Self {
+        Self {
+            text: value.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Index of a pushed range whose end is set when it is popped.
+enum Pushed {
+    Span(usize),
+    Paragraph(usize),
+    Annotation(usize),
+}
+
+/// Builds an [`AnnotatedString`] by appending text, either styling explicit
+/// ranges or pushing styles that apply to everything appended until the
+/// matching [`pop`](AnnotatedStringBuilder::pop).
+#[derive(Default)]
+pub struct AnnotatedStringBuilder {
+    text: String,
+    len: usize,
+    span_styles: Vec<AnnotatedRange<SpanStyle>>,
+    paragraph_styles: Vec<AnnotatedRange<TextParagraphStyle>>,
+    annotations: Vec<AnnotatedRange<StringAnnotation>>,
+    stack: Vec<Pushed>,
+}
+
+impl AnnotatedStringBuilder {
+    #[must_use]
+    pub fn append(mut self, text: &str) -> Self {
+        self.text.push_str(text);
+        self.len += text.chars().count();
+
+        self
+    }
+
+    /// Appends another annotated string, keeping its styles and annotations.
+    #[must_use]
+    pub fn append_annotated(mut self, other: &AnnotatedString) -> Self {
+        let offset = self.len;
+        let shift = |start: usize, end: usize| (offset + start, offset + end);
+
+        for range in other.span_styles() {
+            let (start, end) = shift(range.start, range.end);
+
+            self.span_styles
+                .push(AnnotatedRange::new(range.item.clone(), start, end));
+        }
+
+        for range in other.paragraph_styles() {
+            let (start, end) = shift(range.start, range.end);
+
+            self.paragraph_styles
+                .push(AnnotatedRange::new(range.item.clone(), start, end));
+        }
+
+        for range in other.annotations() {
+            let (start, end) = shift(range.start, range.end);
+
+            self.annotations
+                .push(AnnotatedRange::new(range.item.clone(), start, end));
+        }
+
+        self.append(other.text())
+    }
+
+    #[must_use]
+    pub fn add_style(mut self, style: SpanStyle, start: usize, end: usize) -> Self {
+        self.span_styles
+            .push(AnnotatedRange::new(style, start, end));
+
+        self
+    }
+
+    #[must_use]
+    pub fn add_paragraph_style(
+        mut self,
+        style: TextParagraphStyle,
+        start: usize,
+        end: usize,
+    ) -> Self {
+        self.paragraph_styles
+            .push(AnnotatedRange::new(style, start, end));
+
+        self
+    }
+
+    #[must_use]
+    pub fn add_annotation(
+        mut self,
+        tag: impl Into<Rc<str>>,
+        value: impl Into<Rc<str>>,
+        start: usize,
+        end: usize,
+    ) -> Self {
+        self.annotations.push(AnnotatedRange::new(
+            StringAnnotation {
+                tag: tag.into(),
+                value: value.into(),
+            },
+            start,
+            end,
+        ));
+
+        self
+    }
+
+    #[must_use]
+    pub fn push_style(mut self, style: SpanStyle) -> Self {
+        self.stack.push(Pushed::Span(self.span_styles.len()));
+        self.span_styles
+            .push(AnnotatedRange::new(style, self.len, self.len));
+
+        self
+    }
+
+    #[must_use]
+    pub fn push_paragraph_style(mut self, style: TextParagraphStyle) -> Self {
+        self.stack
+            .push(Pushed::Paragraph(self.paragraph_styles.len()));
+        self.paragraph_styles
+            .push(AnnotatedRange::new(style, self.len, self.len));
+
+        self
+    }
+
+    #[must_use]
+    pub fn push_annotation(mut self, tag: impl Into<Rc<str>>, value: impl Into<Rc<str>>) -> Self {
+        self.stack.push(Pushed::Annotation(self.annotations.len()));
+
+        self.add_annotation(tag, value, self.len, self.len)
+    }
+
+    /// Ends the most recently pushed style or annotation at the current end of
+    /// the text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if nothing was pushed.
+    #[must_use]
+    pub fn pop(mut self) -> Self {
+        let end = self.len;
+
+        match self.stack.pop().expect("nothing to pop") {
+            Pushed::Span(index) => self.span_styles[index].end = end,
+            Pushed::Paragraph(index) => self.paragraph_styles[index].end = end,
+            Pushed::Annotation(index) => self.annotations[index].end = end,
+        }
+
+        self
+    }
+
+    /// Applies `style` to everything appended by `content`.
+    #[must_use]
+    pub fn with_style(self, style: SpanStyle, content: impl FnOnce(Self) -> Self) -> Self {
+        content(self.push_style(style)).pop()
+    }
+
+    /// Annotates everything appended by `content`.
+    #[must_use]
+    pub fn with_annotation(
+        self,
+        tag: impl Into<Rc<str>>,
+        value: impl Into<Rc<str>>,
+        content: impl FnOnce(Self) -> Self,
+    ) -> Self {
+        content(self.push_annotation(tag, value)).pop()
+    }
+
+    /// Finishes the string, ending every style that is still pushed.
+    #[must_use]
+    pub fn build(mut self) -> AnnotatedString {
+        while !self.stack.is_empty() {
+            self = self.pop();
+        }
+
+        AnnotatedString {
+            text: self.text.into(),
+            span_styles: self.span_styles.into(),
+            paragraph_styles: self.paragraph_styles.into(),
+            annotations: self.annotations.into(),
+        }
+    }
+}
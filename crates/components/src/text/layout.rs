@@ -0,0 +1,249 @@
+use std::rc::Rc;
+
+use rumpose_core::prelude::*;
+
+/// Laid out skia paragraph of a text, placed below the previous one.
+pub(crate) struct ParagraphLayout {
+    pub(crate) paragraph: Paragraph,
+    /// Character offset of the paragraph in the whole text.
+    pub(crate) start: usize,
+    pub(crate) text: String,
+    pub(crate) top: f32,
+}
+
+impl ParagraphLayout {
+    fn end(&self) -> usize {
+        self.start + self.text.chars().count()
+    }
+
+    /// Skia reports positions as UTF-16 code unit indices.
+    fn to_utf16(&self, offset: usize) -> usize {
+        self.text.chars().take(offset).map(char::len_utf16).sum()
+    }
+
+    fn from_utf16(&self, index: usize) -> usize {
+        let mut units = 0;
+
+        for (offset, char) in self.text.chars().enumerate() {
+            if units >= index {
+                return offset;
+            }
+
+            units += char.len_utf16();
+        }
+
+        self.text.chars().count()
+    }
+
+    /// Line metrics report UTF-8 byte indices instead.
+    fn from_utf8(&self, index: usize) -> usize {
+        self.text
+            .char_indices()
+            .take_while(|(byte, _)| *byte < index)
+            .count()
+    }
+
+    fn rects(&self, start: usize, end: usize) -> impl Iterator<Item = Rect2D> + '_ {
+        self.paragraph
+            .get_rects_for_range(
+                self.to_utf16(start)..self.to_utf16(end),
+                RectHeightStyle::Max,
+                RectWidthStyle::Tight,
+            )
+            .into_iter()
+            .map(|text_box| {
+                Rect2D::from_xywh(
+                    text_box.rect.left,
+                    self.top + text_box.rect.top,
+                    text_box.rect.width(),
+                    text_box.rect.height(),
+                )
+            })
+    }
+}
+
+/// Single line of a [`TextLayoutResult`]. Offsets count characters of the
+/// whole text and positions are relative to the text origin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextLine {
+    pub start: usize,
+    pub end: usize,
+    pub left: f32,
+    pub top: f32,
+    pub width: f32,
+    pub height: f32,
+    pub baseline: f32,
+}
+
+/// Result of laying out a [`text`](crate::text), shared between measuring
+/// and drawing and passed to
+/// [`TextProps::on_text_layout`](crate::TextProps::on_text_layout).
+/// Offsets count characters and positions are relative to the text origin.
+#[derive(Clone)]
+pub struct TextLayoutResult {
+    paragraphs: Rc<[ParagraphLayout]>,
+    size: Size2D,
+    did_exceed_max_lines: bool,
+}
+
+impl TextLayoutResult {
+    pub(crate) fn new(
+        paragraphs: Vec<ParagraphLayout>,
+        size: Size2D,
+        did_exceed_max_lines: bool,
+    ) -> Self {
+        Self {
+            paragraphs: paragraphs.into(),
+            size,
+            did_exceed_max_lines,
+        }
+    }
+
+    /// Size of the laid out text, before applying the constraints.
+    #[must_use]
+    pub const fn size(&self) -> Size2D {
+        self.size
+    }
+
+    /// Returns `true` if lines were dropped because of
+    /// [`TextProps::max_lines`](crate::TextProps::max_lines).
+    #[must_use]
+    pub const fn did_exceed_max_lines(&self) -> bool {
+        self.did_exceed_max_lines
+    }
+
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.paragraphs
+            .iter()
+            .map(|layout| layout.paragraph.line_number())
+            .sum()
+    }
+
+    #[must_use]
+    pub fn lines(&self) -> Vec<TextLine> {
+        self.paragraphs
+            .iter()
+            .flat_map(|layout| {
+                layout
+                    .paragraph
+                    .get_line_metrics()
+                    .iter()
+                    .map(|metrics| TextLine {
+                        start: layout.start + layout.from_utf8(metrics.start_index),
+                        end: layout.start + layout.from_utf8(metrics.end_index),
+                        left: metrics.left as f32,
+                        top: layout.top + (metrics.baseline - metrics.ascent) as f32,
+                        width: metrics.width as f32,
+                        height: metrics.height as f32,
+                        baseline: layout.top + metrics.baseline as f32,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn paragraph_at(&self, offset: usize) -> Option<&ParagraphLayout> {
+        self.paragraphs
+            .iter()
+            .rev()
+            .find(|layout| layout.start <= offset)
+            .or_else(|| self.paragraphs.first())
+    }
+
+    /// Returns the character offset closest to `position`.
+    #[must_use]
+    pub fn offset_for_position(&self, position: Point2D) -> usize {
+        let Some(layout) = self
+            .paragraphs
+            .iter()
+            .rev()
+            .find(|layout| layout.top <= position.y)
+            .or_else(|| self.paragraphs.first())
+        else {
+            return 0;
+        };
+
+        let index = layout
+            .paragraph
+            .get_glyph_position_at_coordinate((position.x, position.y - layout.top))
+            .position;
+
+        layout.start + layout.from_utf16(usize::try_from(index).unwrap_or_default())
+    }
+
+    /// Returns the bounds of the character at `offset`, if it has any.
+    #[must_use]
+    pub fn bounding_box(&self, offset: usize) -> Option<Rect2D> {
+        let layout = self.paragraph_at(offset)?;
+        let local = offset.checked_sub(layout.start)?;
+
+        layout.rects(local, local + 1).next()
+    }
+
+    /// Returns the zero width rectangle of a cursor placed before the
+    /// character at `offset`.
+    #[must_use]
+    pub fn cursor_rect(&self, offset: usize) -> Rect2D {
+        let Some(layout) = self.paragraph_at(offset) else {
+            return Rect2D::default();
+        };
+        let local = offset.saturating_sub(layout.start);
+
+        if let Some(rect) = layout.rects(local, local + 1).next() {
+            return Rect2D::from_xywh(rect.origin.x, rect.origin.y, 0., rect.size.height);
+        }
+
+        if let Some(rect) = local
+            .checked_sub(1)
+            .and_then(|local| layout.rects(local, local + 1).next())
+        {
+            return Rect2D::from_xywh(rect.max_x(), rect.origin.y, 0., rect.size.height);
+        }
+
+        let height = layout.paragraph.height() / layout.paragraph.line_number().max(1) as f32;
+
+        Rect2D::from_xywh(0., layout.top, 0., height)
+    }
+
+    /// Returns the rectangles covering the characters `start..end`, one per
+    /// line and text direction run.
+    #[must_use]
+    pub fn selection_rects(&self, start: usize, end: usize) -> Vec<Rect2D> {
+        self.paragraphs
+            .iter()
+            .filter(|layout| layout.start < end && start < layout.end())
+            .flat_map(|layout| {
+                layout.rects(
+                    start.saturating_sub(layout.start),
+                    end.min(layout.end()) - layout.start,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the character range of the word at `offset`.
+    #[must_use]
+    pub fn word_boundary(&self, offset: usize) -> (usize, usize) {
+        let Some(layout) = self.paragraph_at(offset) else {
+            return (offset, offset);
+        };
+        let index = layout.to_utf16(offset.saturating_sub(layout.start));
+        let range = layout
+            .paragraph
+            .get_word_boundary(u32::try_from(index).unwrap_or(u32::MAX));
+
+        (
+            layout.start + layout.from_utf16(range.start),
+            layout.start + layout.from_utf16(range.end),
+        )
+    }
+
+    pub(crate) fn paint(&self, canvas: &Canvas, origin: Point2D) {
+        for layout in self.paragraphs.iter() {
+            layout
+                .paragraph
+                .paint(canvas, Point::new(origin.x, origin.y + layout.top));
+        }
+    }
+}
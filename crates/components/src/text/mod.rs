@@ -0,0 +1,474 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use rumpose_core::prelude::*;
+
+mod annotated;
+mod layout;
+mod style;
+
+use self::layout::ParagraphLayout;
+pub use self::{
+    annotated::{AnnotatedRange, AnnotatedString, AnnotatedStringBuilder, StringAnnotation},
+    layout::{TextLayoutResult, TextLine},
+    style::{FontFamily, SpanStyle, TextOverflow, TextParagraphStyle},
+};
+use crate::selection::{self, Selectable, TextLayoutCache};
+
+#[derive(Clone)]
+pub struct TextProps {
+    content: AnnotatedString,
+    color: Option<Color>,
+    font_size: Option<Sp>,
+    font_style: Option<FontSlant>,
+    font_weight: Option<FontWeight>,
+    font_family: Option<FontFamily>,
+    letter_spacing: Option<Sp>,
+    text_decoration: Option<TextDecoration>,
+    text_align: Option<TextAlign>,
+    line_height: Option<f32>,
+    overflow: TextOverflow,
+    soft_wrap: bool,
+    max_lines: usize,
+    min_lines: usize,
+    on_text_layout: Option<Rc<dyn Fn(&TextLayoutResult)>>,
+}
+
+impl Default for TextProps {
+    fn default() -> Self {
+        Self {
+            content: AnnotatedString::default(),
+            color: None,
+            font_size: None,
+            font_style: None,
+            font_weight: None,
+            font_family: None,
+            letter_spacing: None,
+            text_decoration: None,
+            text_align: None,
+            line_height: None,
+            overflow: TextOverflow::default(),
+            soft_wrap: true,
+            max_lines: usize::MAX,
+            min_lines: 1,
+            on_text_layout: None,
+        }
+    }
+}
+
+/// Compares everything affecting the layout, so the
+/// [`on_text_layout`](TextProps::on_text_layout) callback is ignored.
+impl PartialEq for TextProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.content == other.content
+            && self.color == other.color
+            && self.font_size == other.font_size
+            && self.font_style == other.font_style
+            && self.font_weight == other.font_weight
+            && self.font_family == other.font_family
+            && self.letter_spacing == other.letter_spacing
+            && self.text_decoration == other.text_decoration
+            && self.text_align == other.text_align
+            && self.line_height == other.line_height
+            && self.overflow == other.overflow
+            && self.soft_wrap == other.soft_wrap
+            && self.max_lines == other.max_lines
+            && self.min_lines == other.min_lines
+    }
+}
+
+impl TextProps {
+    /// Size of text without [`font_size`](TextProps::font_size), scaled by
+    /// the density like any other.
+    pub const DEFAULT_FONT_SIZE: Sp = Sp(14.);
+
+    #[must_use]
+    pub fn new(content: impl Into<AnnotatedString>) -> Self {
+        Self {
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+
+        self
+    }
+
+    #[must_use]
+    pub fn font_size(mut self, value: impl Into<Sp>) -> Self {
+        self.font_size = Some(value.into());
+
+        self
+    }
+
+    #[must_use]
+    pub const fn font_style(mut self, value: FontSlant) -> Self {
+        self.font_style = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn font_weight(mut self, value: FontWeight) -> Self {
+        self.font_weight = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub fn font_family(mut self, value: FontFamily) -> Self {
+        self.font_family = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub fn letter_spacing(mut self, value: impl Into<Sp>) -> Self {
+        self.letter_spacing = Some(value.into());
+
+        self
+    }
+
+    #[must_use]
+    pub const fn text_decoration(mut self, value: TextDecoration) -> Self {
+        self.text_decoration = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn text_align(mut self, value: TextAlign) -> Self {
+        self.text_align = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn line_height(mut self, value: f32) -> Self {
+        self.line_height = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn overflow(mut self, value: TextOverflow) -> Self {
+        self.overflow = value;
+
+        self
+    }
+
+    /// When disabled, lines only break at explicit line breaks and the text
+    /// is measured at its full width.
+    #[must_use]
+    pub const fn soft_wrap(mut self, value: bool) -> Self {
+        self.soft_wrap = value;
+
+        self
+    }
+
+    /// Limits the number of lines, counted over all paragraphs.
+    #[must_use]
+    pub const fn max_lines(mut self, value: usize) -> Self {
+        self.max_lines = value;
+
+        self
+    }
+
+    /// Reserves the height of at least this many lines.
+    #[must_use]
+    pub const fn min_lines(mut self, value: usize) -> Self {
+        self.min_lines = value;
+
+        self
+    }
+
+    /// Called with every new layout of the text.
+    #[must_use]
+    pub fn on_text_layout(mut self, callback: impl Fn(&TextLayoutResult) + 'static) -> Self {
+        self.on_text_layout = Some(Rc::new(callback));
+
+        self
+    }
+
+    fn span_style(&self) -> SpanStyle {
+        SpanStyle {
+            color: self.color,
+            font_size: Some(self.font_size.unwrap_or(Self::DEFAULT_FONT_SIZE)),
+            font_weight: self.font_weight,
+            font_slant: self.font_style,
+            font_family: self.font_family.clone(),
+            letter_spacing: self.letter_spacing,
+            decoration: self.text_decoration,
+            background: None,
+        }
+    }
+
+    fn paragraph_style(&self) -> TextParagraphStyle {
+        TextParagraphStyle {
+            text_align: self.text_align,
+            text_direction: None,
+            line_height: self.line_height,
+        }
+    }
+
+    /// Returns the paragraph ranges, additionally split at every line break
+    /// when soft wrapping is disabled so each one holds a single line.
+    fn ranges(&self) -> Vec<(usize, usize, Option<&TextParagraphStyle>)> {
+        let paragraphs = self.content.paragraphs();
+
+        if self.soft_wrap {
+            return paragraphs;
+        }
+
+        paragraphs
+            .into_iter()
+            .flat_map(|(start, end, style)| {
+                let mut ranges = Vec::new();
+                let mut from = start;
+
+                for (index, char) in self.content.slice(start, end).chars().enumerate() {
+                    if char == '\n' {
+                        ranges.push((from, start + index, style));
+                        from = start + index + 1;
+                    }
+                }
+
+                ranges.push((from, end, style));
+
+                ranges
+            })
+            .collect()
+    }
+
+    /// Builds one skia paragraph for the characters `start..end`, pushing the
+    /// merged span styles of every segment on top of the base style.
+    fn build_paragraph(
+        &self,
+        (start, end, paragraph): (usize, usize, Option<&TextParagraphStyle>),
+        max_lines: usize,
+        font_manager: &FontCollection,
+        density: Density,
+    ) -> Paragraph {
+        let content = &self.content;
+        let mut style = TextStyle::new();
+        let mut paragraph_style = ParagraphStyle::new();
+
+        self.span_style().apply(&mut style, density);
+        self.paragraph_style()
+            .apply(&mut paragraph_style, &mut style);
+
+        if let Some(paragraph) = paragraph {
+            paragraph.apply(&mut paragraph_style, &mut style);
+        }
+
+        paragraph_style
+            .set_text_style(&style)
+            .set_max_lines(max_lines);
+
+        if self.overflow == TextOverflow::Ellipsis {
+            paragraph_style.set_ellipsis("\u{2026}");
+        }
+
+        let mut builder = ParagraphBuilder::new(&paragraph_style, font_manager);
+
+        for (from, to, spans) in content.segments(start, end) {
+            let mut span_style = style.clone();
+
+            for span in spans {
+                span.apply(&mut span_style, density);
+            }
+
+            builder
+                .push_style(&span_style)
+                .add_text(content.slice(from, to))
+                .pop();
+        }
+
+        builder.build()
+    }
+
+    /// Builds and lays out the paragraphs, stopping once `max_lines` is
+    /// reached, then lays them out again at the width the text takes within
+    /// `constraints` so alignment is relative to its final bounds.
+    pub(crate) fn layout(
+        &self,
+        font_manager: &FontCollection,
+        density: Density,
+        constraints: Constraints,
+    ) -> TextLayoutResult {
+        // Without soft wrapping every paragraph is a single line, which is
+        // only laid out at the maximum width to ellipsize it.
+        let unbounded = !self.soft_wrap && self.overflow != TextOverflow::Ellipsis;
+        let ranges = self.ranges();
+        let mut did_exceed_max_lines = false;
+        let mut remaining = self.max_lines;
+        let mut paragraphs = Vec::new();
+
+        for range in &ranges {
+            if remaining == 0 {
+                did_exceed_max_lines = true;
+
+                break;
+            }
+
+            let mut paragraph = self.build_paragraph(*range, remaining, font_manager, density);
+
+            paragraph.layout(if unbounded {
+                f32::INFINITY
+            } else {
+                constraints.max.width
+            });
+
+            did_exceed_max_lines |= paragraph.did_exceed_max_lines();
+            remaining = remaining.saturating_sub(paragraph.line_number());
+            paragraphs.push((range.0, range.1, paragraph));
+        }
+
+        let content = paragraphs
+            .iter()
+            .map(|(_, _, paragraph)| {
+                if unbounded {
+                    paragraph.max_intrinsic_width().ceil()
+                } else {
+                    paragraph.longest_line()
+                }
+            })
+            .fold(0., f32::max);
+        let width = if unbounded {
+            constraints.apply_width(content).max(content)
+        } else {
+            constraints
+                .apply_width(content)
+                .ceil()
+                .min(constraints.max.width)
+        };
+
+        let mut size = Size2D::new(0., 0.);
+        let mut lines = 0;
+        let paragraphs = paragraphs
+            .into_iter()
+            .map(|(start, end, mut paragraph)| {
+                paragraph.layout(width);
+
+                let top = size.height;
+
+                size.width = size.width.max(paragraph.longest_line());
+                size.height += paragraph.height();
+                lines += paragraph.line_number();
+
+                ParagraphLayout {
+                    paragraph,
+                    start,
+                    text: self.content.slice(start, end).to_owned(),
+                    top,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(last) = paragraphs.last().filter(|_| lines < self.min_lines) {
+            let line_height = last.paragraph.height() / last.paragraph.line_number().max(1) as f32;
+
+            size.height += (self.min_lines - lines) as f32 * line_height;
+        }
+
+        TextLayoutResult::new(paragraphs, size, did_exceed_max_lines)
+    }
+}
+
+#[track_caller]
+#[allow(clippy::missing_panics_doc)]
+pub fn text(scope: Scope, props: TextProps) {
+    // The layout survives recompositions until the content or style change,
+    // and is reused while the constraints and density stay the same.
+    let caches = scope
+        .use_state(|| Rc::new(RefCell::new(None::<(TextProps, TextLayoutCache)>)))
+        .with(Clone::clone);
+    let cache = {
+        let mut caches = caches.borrow_mut();
+
+        match &*caches {
+            Some((key, cache)) if *key == props => cache.clone(),
+            _ => {
+                let cache: TextLayoutCache = Rc::default();
+
+                *caches = Some((props.clone(), cache.clone()));
+
+                cache
+            }
+        }
+    };
+    let selectable = Rc::new(Selectable {
+        content: props.content.clone(),
+        layout: cache.clone(),
+        area: Cell::default(),
+    });
+    let measure_props = props.clone();
+    let measure_cache = cache.clone();
+
+    draw(
+        scope,
+        move |context| {
+            let area = context.area();
+
+            selectable.area.set(area);
+            selection::register(&selectable);
+
+            let result = match &*cache.borrow() {
+                Some((_, _, result)) => result.clone(),
+                None => props.layout(
+                    context.font_manager(),
+                    context.density(),
+                    Constraints::new(0., area.size.width, 0., area.size.height),
+                ),
+            };
+
+            context.with_canvas(|canvas| {
+                canvas.save();
+
+                if props.overflow != TextOverflow::Visible {
+                    canvas.clip_rect(Rect::from(area), None, Some(true));
+                }
+
+                result.paint(canvas, area.origin);
+
+                canvas.restore();
+            });
+        },
+        move |scope| {
+            let props = measure_props.clone();
+            let cache = measure_cache.clone();
+
+            layout(
+                scope,
+                move |_, context, constraints| {
+                    let density = context.density();
+                    let mut cache = cache.borrow_mut();
+
+                    if let Some((_, _, result)) = cache.as_ref().filter(|(key, key_density, _)| {
+                        *key == constraints && *key_density == density
+                    }) {
+                        return constraints.apply(result.size());
+                    }
+
+                    let result = props.layout(context.font_manager(), density, constraints);
+
+                    if let Some(on_text_layout) = &props.on_text_layout {
+                        on_text_layout(&result);
+                    }
+
+                    let size = constraints.apply(result.size());
+
+                    *cache = Some((constraints, density, result));
+
+                    size
+                },
+                |_| {},
+            );
+        },
+    );
+}
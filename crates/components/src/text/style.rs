@@ -0,0 +1,226 @@
+use std::rc::Rc;
+
+use rumpose_core::prelude::*;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum FontFamily {
+    /// Default family of the context font collection.
+    #[default]
+    Default,
+    SansSerif,
+    Serif,
+    Monospace,
+    Cursive,
+    /// Family registered in the [`FontRegistry`] or installed on the system.
+    Named(Rc<str>),
+}
+
+impl FontFamily {
+    #[must_use]
+    pub fn named(name: impl Into<Rc<str>>) -> Self {
+        Self::Named(name.into())
+    }
+
+    fn name(&self) -> Option<&str> {
+        match self {
+            Self::Default => None,
+            Self::SansSerif => Some("sans-serif"),
+            Self::Serif => Some("serif"),
+            Self::Monospace => Some("monospace"),
+            Self::Cursive => Some("cursive"),
+            Self::Named(name) => Some(name),
+        }
+    }
+}
+
+/// How text that does not fit its bounds is shown.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TextOverflow {
+    /// Clips the text to its bounds.
+    #[default]
+    Clip,
+    /// Ends the last visible line with an ellipsis.
+    Ellipsis,
+    /// Draws the text past its bounds.
+    Visible,
+}
+
+/// Character level styling applied to a range of an
+/// [`AnnotatedString`](crate::AnnotatedString). Unset fields are inherited
+/// from the enclosing styles.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SpanStyle {
+    pub color: Option<Color>,
+    pub font_size: Option<Sp>,
+    pub font_weight: Option<FontWeight>,
+    pub font_slant: Option<FontSlant>,
+    pub font_family: Option<FontFamily>,
+    pub letter_spacing: Option<Sp>,
+    pub decoration: Option<TextDecoration>,
+    pub background: Option<Color>,
+}
+
+impl SpanStyle {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+
+        self
+    }
+
+    #[must_use]
+    pub fn font_size(mut self, value: impl Into<Sp>) -> Self {
+        self.font_size = Some(value.into());
+
+        self
+    }
+
+    #[must_use]
+    pub const fn font_weight(mut self, value: FontWeight) -> Self {
+        self.font_weight = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn bold(self) -> Self {
+        self.font_weight(FontWeight::BOLD)
+    }
+
+    #[must_use]
+    pub const fn italic(mut self) -> Self {
+        self.font_slant = Some(FontSlant::Italic);
+
+        self
+    }
+
+    #[must_use]
+    pub fn font_family(mut self, value: FontFamily) -> Self {
+        self.font_family = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub fn letter_spacing(mut self, value: impl Into<Sp>) -> Self {
+        self.letter_spacing = Some(value.into());
+
+        self
+    }
+
+    #[must_use]
+    pub const fn decoration(mut self, value: TextDecoration) -> Self {
+        self.decoration = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn underline(self) -> Self {
+        self.decoration(TextDecoration::UNDERLINE)
+    }
+
+    #[must_use]
+    pub const fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+
+        self
+    }
+
+    pub(crate) fn apply(&self, style: &mut TextStyle, density: Density) {
+        if let Some(color) = self.color {
+            style.set_color(color.to_engine());
+        }
+
+        if let Some(value) = self.font_size {
+            style.set_font_size(value.to_px(density));
+        }
+
+        if self.font_weight.is_some() || self.font_slant.is_some() {
+            let current = style.font_style();
+
+            style.set_font_style(FontStyle::new(
+                self.font_weight.unwrap_or_else(|| current.weight()),
+                current.width(),
+                self.font_slant.unwrap_or_else(|| current.slant()),
+            ));
+        }
+
+        if let Some(name) = self.font_family.as_ref().and_then(FontFamily::name) {
+            style.set_font_families(&[name]);
+        }
+
+        if let Some(value) = self.letter_spacing {
+            style.set_letter_spacing(value.to_px(density));
+        }
+
+        if let Some(value) = self.decoration {
+            style.set_decoration_type(value);
+        }
+
+        if let Some(color) = self.background {
+            let mut paint = Paint::default();
+
+            paint.set_color4f(color.to_engine4f(), None);
+
+            style.set_background_paint(&paint);
+        }
+    }
+}
+
+/// Paragraph level styling applied to a range of an
+/// [`AnnotatedString`](crate::AnnotatedString). Each styled range is laid out
+/// as its own paragraph below the previous one.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TextParagraphStyle {
+    pub text_align: Option<TextAlign>,
+    pub text_direction: Option<TextDirection>,
+    pub line_height: Option<f32>,
+}
+
+impl TextParagraphStyle {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn text_align(mut self, value: TextAlign) -> Self {
+        self.text_align = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn text_direction(mut self, value: TextDirection) -> Self {
+        self.text_direction = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn line_height(mut self, value: f32) -> Self {
+        self.line_height = Some(value);
+
+        self
+    }
+
+    pub(crate) fn apply(&self, paragraph: &mut ParagraphStyle, style: &mut TextStyle) {
+        if let Some(value) = self.text_align {
+            paragraph.set_text_align(value);
+        }
+
+        if let Some(value) = self.text_direction {
+            paragraph.set_text_direction(value);
+        }
+
+        if let Some(value) = self.line_height {
+            style.set_height_override(true).set_height(value);
+        }
+    }
+}
@@ -0,0 +1,566 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use rumpose_core::prelude::*;
+
+mod options;
+mod value;
+
+pub use self::{
+    options::{
+        ImeAction, KeyboardCapitalization, KeyboardOptions, KeyboardType,
+        PasswordVisualTransformation, VisualTransformation,
+    },
+    value::{TextFieldValue, TextRange},
+};
+use crate::{
+    AnnotatedString, Clipboard, FontFamily, TextLayoutResult, TextOverflow, TextProps, text,
+};
+
+#[derive(Clone)]
+pub struct TextFieldProps {
+    color: Option<Color>,
+    font_size: Option<Sp>,
+    font_family: Option<FontFamily>,
+    cursor_color: Color,
+    selection_color: Color,
+    placeholder: Option<Rc<str>>,
+    placeholder_color: Color,
+    single_line: bool,
+    max_lines: usize,
+    read_only: bool,
+    focus_requester: Option<FocusRequester>,
+    on_focus_changed: Option<Rc<dyn Fn(bool)>>,
+    keyboard_options: KeyboardOptions,
+    visual_transformation: Option<Rc<dyn VisualTransformation>>,
+    clipboard: Option<Rc<dyn Clipboard>>,
+    on_ime_action: Option<Rc<dyn Fn(ImeAction)>>,
+}
+
+impl Default for TextFieldProps {
+    fn default() -> Self {
+        Self {
+            color: None,
+            font_size: None,
+            font_family: None,
+            cursor_color: Color::BLACK,
+            selection_color: Color::from_argb(0x66, 0x33, 0x66, 0xFF),
+            placeholder: None,
+            placeholder_color: Color::GRAY,
+            single_line: false,
+            max_lines: usize::MAX,
+            read_only: false,
+            focus_requester: None,
+            on_focus_changed: None,
+            keyboard_options: KeyboardOptions::new(),
+            visual_transformation: None,
+            clipboard: None,
+            on_ime_action: None,
+        }
+    }
+}
+
+impl TextFieldProps {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+
+        self
+    }
+
+    #[must_use]
+    pub fn font_size(mut self, value: impl Into<Sp>) -> Self {
+        self.font_size = Some(value.into());
+
+        self
+    }
+
+    #[must_use]
+    pub fn font_family(mut self, value: FontFamily) -> Self {
+        self.font_family = Some(value);
+
+        self
+    }
+
+    #[must_use]
+    pub const fn cursor_color(mut self, color: Color) -> Self {
+        self.cursor_color = color;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn selection_color(mut self, color: Color) -> Self {
+        self.selection_color = color;
+
+        self
+    }
+
+    /// Text shown while the value is empty.
+    #[must_use]
+    pub fn placeholder(mut self, value: impl Into<Rc<str>>) -> Self {
+        self.placeholder = Some(value.into());
+
+        self
+    }
+
+    #[must_use]
+    pub const fn placeholder_color(mut self, color: Color) -> Self {
+        self.placeholder_color = color;
+
+        self
+    }
+
+    /// Keeps the text on one line, which scrolls horizontally to keep the
+    /// cursor visible instead of wrapping. Enter runs the IME action instead of
+    /// inserting a line break.
+    #[must_use]
+    pub const fn single_line(mut self, value: bool) -> Self {
+        self.single_line = value;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn max_lines(mut self, value: usize) -> Self {
+        self.max_lines = value;
+
+        self
+    }
+
+    /// Allows selecting and copying, but not editing the text.
+    #[must_use]
+    pub const fn read_only(mut self, value: bool) -> Self {
+        self.read_only = value;
+
+        self
+    }
+
+    /// Requester moving the focus to the field, which also takes it when
+    /// pressed or reached with the tab key.
+    #[must_use]
+    pub fn focus_requester(mut self, value: FocusRequester) -> Self {
+        self.focus_requester = Some(value);
+
+        self
+    }
+
+    /// Called when the field gains or loses the focus.
+    #[must_use]
+    pub fn on_focus_changed(mut self, callback: impl Fn(bool) + 'static) -> Self {
+        self.on_focus_changed = Some(Rc::new(callback));
+
+        self
+    }
+
+    #[must_use]
+    pub const fn keyboard_options(mut self, value: KeyboardOptions) -> Self {
+        self.keyboard_options = value;
+
+        self
+    }
+
+    #[must_use]
+    pub fn visual_transformation(mut self, value: impl VisualTransformation + 'static) -> Self {
+        self.visual_transformation = Some(Rc::new(value));
+
+        self
+    }
+
+    /// Clipboard used for copy, cut and paste shortcuts, which do nothing
+    /// without one.
+    #[must_use]
+    pub fn clipboard(mut self, value: Rc<dyn Clipboard>) -> Self {
+        self.clipboard = Some(value);
+
+        self
+    }
+
+    /// Called when enter is pressed in a single line field, with the action
+    /// of the keyboard options.
+    #[must_use]
+    pub fn on_ime_action(mut self, callback: impl Fn(ImeAction) + 'static) -> Self {
+        self.on_ime_action = Some(Rc::new(callback));
+
+        self
+    }
+
+    fn to_transformed(&self, text: &str, offset: usize) -> usize {
+        self.visual_transformation
+            .as_ref()
+            .map_or(offset, |value| value.original_to_transformed(text, offset))
+    }
+
+    fn to_original(&self, text: &str, offset: usize) -> usize {
+        self.visual_transformation
+            .as_ref()
+            .map_or(offset, |value| value.transformed_to_original(text, offset))
+    }
+
+    fn text_props(&self, value: &TextFieldValue) -> TextProps {
+        let placeholder = self.placeholder.as_ref().filter(|_| value.is_empty());
+        let content = match (placeholder, &self.visual_transformation) {
+            (Some(placeholder), _) => AnnotatedString::from(&**placeholder),
+            (None, Some(transformation)) => transformation.transform(&value.text),
+            (None, None) => AnnotatedString::from(value.text.as_str()),
+        };
+        let mut props = TextProps::new(content)
+            .soft_wrap(!self.single_line)
+            .max_lines(if self.single_line { 1 } else { self.max_lines });
+
+        // A single line is clipped by the field, which scrolls it.
+        if self.single_line {
+            props = props.overflow(TextOverflow::Visible);
+        }
+
+        let color = if placeholder.is_some() {
+            Some(self.placeholder_color)
+        } else {
+            self.color
+        };
+
+        if let Some(color) = color {
+            props = props.color(color);
+        }
+
+        if let Some(value) = self.font_size {
+            props = props.font_size(value);
+        }
+
+        if let Some(value) = self.font_family.clone() {
+            props = props.font_family(value);
+        }
+
+        props
+    }
+
+    /// Copying is disabled for passwords and transformed text, which would
+    /// reveal what the transformation hides.
+    fn copy(&self, value: &TextFieldValue) -> bool {
+        let allowed = self.visual_transformation.is_none()
+            && self.keyboard_options.keyboard_type != KeyboardType::Password;
+
+        if let Some(clipboard) = self.clipboard.as_ref().filter(|_| allowed) {
+            if !value.selection.is_collapsed() {
+                clipboard.set_text(value.selected_text());
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn insert(&self, value: &TextFieldValue, text: &str) -> TextFieldValue {
+        if self.read_only {
+            return value.clone();
+        }
+
+        let text = if self.single_line {
+            text.replace('\n', " ")
+        } else {
+            text.to_owned()
+        };
+        let (before, after) = value.split_at_selection();
+
+        value.replace_selection(&self.keyboard_options.filter(&text, before, after))
+    }
+
+    fn line_bounds(
+        &self,
+        value: &TextFieldValue,
+        layout: Option<&TextLayoutResult>,
+        offset: usize,
+    ) -> (usize, usize) {
+        let hard = (value.line_start(offset), value.line_end(offset));
+        let Some(layout) = layout else {
+            return hard;
+        };
+        let transformed = self.to_transformed(&value.text, offset);
+
+        layout
+            .lines()
+            .into_iter()
+            .find(|line| line.start <= transformed && transformed <= line.end)
+            .map_or(hard, |line| {
+                (
+                    self.to_original(&value.text, line.start).max(hard.0),
+                    self.to_original(&value.text, line.end).min(hard.1),
+                )
+            })
+    }
+
+    /// Returns the offset `lines` lines above or below `offset`, keeping its
+    /// horizontal position.
+    fn vertical(
+        &self,
+        value: &TextFieldValue,
+        layout: &TextLayoutResult,
+        offset: usize,
+        lines: f32,
+    ) -> usize {
+        let rect = layout.cursor_rect(self.to_transformed(&value.text, offset));
+        let position = Point2D::new(
+            rect.origin.x,
+            rect.origin.y + rect.size.height * (0.5 + lines),
+        );
+
+        self.to_original(&value.text, layout.offset_for_position(position))
+    }
+
+    fn on_key(
+        &self,
+        event: &KeyEvent,
+        value: &State<TextFieldValue>,
+        layout: Option<&TextLayoutResult>,
+    ) -> bool {
+        if event.kind != KeyEventType::KeyDown {
+            return false;
+        }
+
+        let current = value.with(Clone::clone);
+        let modifiers = event.modifiers;
+        let word = modifiers.control || modifiers.alt;
+        let extend = modifiers.shift;
+        let selection = current.selection;
+        let cursor = selection.end;
+        let editable = |next: TextFieldValue| {
+            if self.read_only {
+                current.clone()
+            } else {
+                next
+            }
+        };
+
+        let next = match event.key {
+            Key::Character(char) if modifiers.is_shortcut() => match char.to_ascii_lowercase() {
+                'a' => current.select_all(),
+                'c' => return self.copy(&current),
+                'x' => {
+                    if !self.copy(&current) {
+                        return false;
+                    }
+
+                    editable(current.replace_selection(""))
+                }
+                'v' => match self
+                    .clipboard
+                    .as_ref()
+                    .and_then(|clipboard| clipboard.text())
+                {
+                    Some(text) => self.insert(&current, &text),
+                    None => return false,
+                },
+                _ => return false,
+            },
+            Key::Character(char) => self.insert(&current, char.encode_utf8(&mut [0; 4])),
+            Key::Enter if self.single_line => {
+                if let Some(on_ime_action) = &self.on_ime_action {
+                    on_ime_action(self.keyboard_options.ime_action);
+                }
+
+                return true;
+            }
+            Key::Enter => self.insert(&current, "\n"),
+            Key::Backspace => editable(current.delete_backward(word)),
+            Key::Delete => editable(current.delete_forward(word)),
+            Key::ArrowLeft if !extend && !word && !selection.is_collapsed() => {
+                current.move_cursor(selection.min(), false)
+            }
+            Key::ArrowRight if !extend && !word && !selection.is_collapsed() => {
+                current.move_cursor(selection.max(), false)
+            }
+            Key::ArrowLeft if word => current.move_cursor(current.previous_word(cursor), extend),
+            Key::ArrowRight if word => current.move_cursor(current.next_word(cursor), extend),
+            Key::ArrowLeft => current.move_cursor(cursor.saturating_sub(1), extend),
+            Key::ArrowRight => current.move_cursor(cursor + 1, extend),
+            Key::Home if modifiers.control => current.move_cursor(0, extend),
+            Key::End if modifiers.control => current.move_cursor(current.len(), extend),
+            Key::Home => current.move_cursor(self.line_bounds(&current, layout, cursor).0, extend),
+            Key::End => current.move_cursor(self.line_bounds(&current, layout, cursor).1, extend),
+            Key::ArrowUp | Key::ArrowDown if !self.single_line => {
+                let Some(layout) = layout else {
+                    return false;
+                };
+                let lines = if event.key == Key::ArrowUp { -1. } else { 1. };
+
+                current.move_cursor(self.vertical(&current, layout, cursor, lines), extend)
+            }
+            _ => return false,
+        };
+
+        if next != current {
+            value.set(next);
+        }
+
+        true
+    }
+}
+
+/// Editable text without decoration. Typed text, editing and navigation keys
+/// and clipboard shortcuts are handled while the field has the focus,
+/// updating `value`.
+#[track_caller]
+pub fn basic_text_field(scope: Scope, value: State<TextFieldValue>, props: TextFieldProps) {
+    let current = value.with(Clone::clone);
+    let text_layout = Rc::new(RefCell::new(None::<TextLayoutResult>));
+    let text_props = {
+        let text_layout = text_layout.clone();
+
+        props.text_props(&current).on_text_layout(move |result| {
+            text_layout.replace(Some(result.clone()));
+        })
+    };
+    // Horizontal scroll of a single line, kept while the field recomposes.
+    let scroll = scope
+        .use_state(|| Rc::new(Cell::new(0.)))
+        .with(Clone::clone);
+    let focus = scope.use_state(|| false);
+    // Read while composing, so the cursor is drawn again when the focus
+    // changes.
+    let focused = focus.with(|focused| *focused);
+    let requester = FocusRequester::new(scope);
+    let on_focus_changed = props.on_focus_changed.clone();
+    let focus_props = props
+        .focus_requester
+        .clone()
+        .into_iter()
+        .fold(
+            FocusProps::new().requester(requester.clone()),
+            FocusProps::requester,
+        )
+        .on_focus_changed(move |focused| {
+            focus.set(focused);
+
+            if let Some(on_focus_changed) = &on_focus_changed {
+                on_focus_changed(focused);
+            }
+        });
+    let key_props = props.clone();
+    let key_layout = text_layout.clone();
+
+    let content = move |scope: Scope| {
+        let props = props.clone();
+        let current = current.clone();
+        let text_layout = text_layout.clone();
+        let text_props = text_props.clone();
+        let scroll = scroll.clone();
+
+        draw(
+            scope,
+            move |context| {
+                let area = context.area();
+                let layout = text_layout.borrow().clone();
+                let selection = current.selection;
+                let start = props.to_transformed(&current.text, selection.min());
+                let end = props.to_transformed(&current.text, selection.max());
+                let cursor_width = Dp(2.).to_px(context.density());
+
+                context.set_do_content_draw(false);
+
+                // Canvas state to restore once the scrolled content is drawn.
+                let save_count = layout.as_ref().filter(|_| props.single_line).map(|layout| {
+                    let cursor = layout
+                        .cursor_rect(props.to_transformed(&current.text, selection.end))
+                        .origin
+                        .x;
+                    let overflow = layout.size().width + cursor_width - area.size.width;
+
+                    scroll.set(
+                        scroll
+                            .get()
+                            .min(cursor)
+                            .max(cursor + cursor_width - area.size.width)
+                            .min(overflow)
+                            .max(0.),
+                    );
+
+                    context.with_canvas(|canvas| {
+                        let save_count = canvas.save();
+
+                        canvas.clip_rect(Rect::from(area), None, Some(true));
+                        canvas.translate((-scroll.get(), 0.));
+
+                        save_count
+                    })
+                });
+
+                if let Some(layout) = layout.as_ref().filter(|_| focused) {
+                    context.with_canvas(|canvas| {
+                        let mut paint = Paint::default();
+
+                        paint.set_color4f(props.selection_color.to_engine4f(), None);
+
+                        for rect in layout.selection_rects(start, end) {
+                            canvas.draw_rect(
+                                Rect::from(Rect2D::new(rect.origin + area.origin, rect.size)),
+                                &paint,
+                            );
+                        }
+                    });
+                }
+
+                context.draw_content();
+
+                if let Some(layout) = layout.as_ref().filter(|_| focused) {
+                    let rect =
+                        layout.cursor_rect(props.to_transformed(&current.text, selection.end));
+                    let rect = Rect2D::from_xywh(
+                        area.origin.x + rect.origin.x,
+                        area.origin.y + rect.origin.y,
+                        cursor_width,
+                        rect.size.height,
+                    );
+
+                    context.with_canvas(|canvas| {
+                        let mut paint = Paint::default();
+
+                        paint.set_color4f(props.cursor_color.to_engine4f(), None);
+
+                        canvas.draw_rect(Rect::from(rect), &paint);
+                    });
+                }
+
+                if let Some(save_count) = save_count {
+                    context.with_canvas(|canvas| {
+                        canvas.restore_to_count(save_count);
+                    });
+                }
+            },
+            move |scope| text(scope, text_props.clone()),
+        );
+    };
+
+    focus_target(scope, focus_props, move |scope| {
+        let requester = requester.clone();
+        let key_props = key_props.clone();
+        let key_layout = key_layout.clone();
+        let content = content.clone();
+
+        pointer_input(
+            scope,
+            move |event, pass| {
+                if pass == PointerEventPass::Main && event.kind == PointerEventType::Press {
+                    requester.request_focus();
+                }
+            },
+            move |scope| {
+                let key_props = key_props.clone();
+                let key_layout = key_layout.clone();
+
+                key_input(
+                    scope,
+                    move |event| key_props.on_key(event, &value, key_layout.borrow().as_ref()),
+                    content.clone(),
+                );
+            },
+        );
+    });
+}
@@ -0,0 +1,182 @@
+use std::fmt::Debug;
+
+use crate::AnnotatedString;
+
+/// Kind of content a text field expects. Restricted types drop typed and
+/// pasted characters they do not accept.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyboardType {
+    #[default]
+    Text,
+    Ascii,
+    Number,
+    Decimal,
+    Phone,
+    Uri,
+    Email,
+    Password,
+}
+
+impl KeyboardType {
+    /// `has_point` tells whether the text kept around the insertion already
+    /// holds a decimal point.
+    fn accepts(self, char: char, has_point: bool) -> bool {
+        match self {
+            Self::Text | Self::Password => true,
+            Self::Ascii | Self::Uri | Self::Email => char.is_ascii(),
+            Self::Number => char.is_ascii_digit(),
+            Self::Decimal => char.is_ascii_digit() || (char == '.' && !has_point),
+            Self::Phone => char.is_ascii_digit() || "+-() *#".contains(char),
+        }
+    }
+}
+
+/// Action of a single line text field run on enter.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImeAction {
+    #[default]
+    Default,
+    Done,
+    Go,
+    Next,
+    Previous,
+    Search,
+    Send,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyboardCapitalization {
+    #[default]
+    None,
+    Characters,
+    Words,
+    Sentences,
+}
+
+impl KeyboardCapitalization {
+    fn applies(self, before: &str) -> bool {
+        let trimmed = before.trim_end();
+
+        match self {
+            Self::None => false,
+            Self::Characters => true,
+            Self::Words => before.is_empty() || before.ends_with(char::is_whitespace),
+            Self::Sentences => {
+                trimmed.is_empty()
+                    || (trimmed.len() < before.len() && trimmed.ends_with(['.', '!', '?']))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyboardOptions {
+    pub keyboard_type: KeyboardType,
+    pub ime_action: ImeAction,
+    pub capitalization: KeyboardCapitalization,
+    /// Hint for hosts running an input method; typed text is not corrected.
+    pub auto_correct: bool,
+}
+
+impl Default for KeyboardOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyboardOptions {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            keyboard_type: KeyboardType::Text,
+            ime_action: ImeAction::Default,
+            capitalization: KeyboardCapitalization::None,
+            auto_correct: true,
+        }
+    }
+
+    #[must_use]
+    pub const fn keyboard_type(mut self, value: KeyboardType) -> Self {
+        self.keyboard_type = value;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn ime_action(mut self, value: ImeAction) -> Self {
+        self.ime_action = value;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn capitalization(mut self, value: KeyboardCapitalization) -> Self {
+        self.capitalization = value;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn auto_correct(mut self, value: bool) -> Self {
+        self.auto_correct = value;
+
+        self
+    }
+
+    /// Drops the characters of `input` the keyboard type does not accept and
+    /// capitalizes it, given the text kept `before` and `after` the replaced
+    /// selection.
+    pub(crate) fn filter(&self, input: &str, before: &str, after: &str) -> String {
+        let mut has_point = before.contains('.') || after.contains('.');
+        let mut before = before.to_owned();
+        let mut result = String::new();
+
+        for char in input.chars() {
+            if !self.keyboard_type.accepts(char, has_point) {
+                continue;
+            }
+
+            has_point |= char == '.';
+
+            if self.capitalization.applies(&before) {
+                result.extend(char.to_uppercase());
+            } else {
+                result.push(char);
+            }
+
+            before.push(char);
+        }
+
+        result
+    }
+}
+
+/// Changes how the text of a text field is displayed without changing its
+/// value, e.g. masking passwords. Offsets count characters.
+pub trait VisualTransformation: Debug {
+    fn transform(&self, text: &str) -> AnnotatedString;
+
+    fn original_to_transformed(&self, _text: &str, offset: usize) -> usize {
+        offset
+    }
+
+    fn transformed_to_original(&self, _text: &str, offset: usize) -> usize {
+        offset
+    }
+}
+
+/// Replaces every character with a mask character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordVisualTransformation(pub char);
+
+impl Default for PasswordVisualTransformation {
+    fn default() -> Self {
+        Self('\u{2022}')
+    }
+}
+
+impl VisualTransformation for PasswordVisualTransformation {
+    fn transform(&self, text: &str) -> AnnotatedString {
+        text.chars().map(|_| self.0).collect::<String>().into()
+    }
+}
@@ -0,0 +1,364 @@
+/// Character range from `start`, the anchor, to `end`, where the cursor is.
+/// `end` can come before `start` when selecting backwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl TextRange {
+    #[must_use]
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    #[must_use]
+    pub const fn collapsed(offset: usize) -> Self {
+        Self::new(offset, offset)
+    }
+
+    #[must_use]
+    pub const fn min(&self) -> usize {
+        if self.start < self.end {
+            self.start
+        } else {
+            self.end
+        }
+    }
+
+    #[must_use]
+    pub const fn max(&self) -> usize {
+        if self.start < self.end {
+            self.end
+        } else {
+            self.start
+        }
+    }
+
+    #[must_use]
+    pub const fn is_collapsed(&self) -> bool {
+        self.start == self.end
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.max() - self.min()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.is_collapsed()
+    }
+}
+
+/// Text of a text field with its selection. Offsets count characters.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct TextFieldValue {
+    pub text: String,
+    pub selection: TextRange,
+}
+
+fn is_word_char(char: char) -> bool {
+    char.is_alphanumeric() || char == '_'
+}
+
+impl TextFieldValue {
+    /// Creates a value with the cursor at the end of `text`.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let len = text.chars().count();
+
+        Self {
+            text,
+            selection: TextRange::collapsed(len),
+        }
+    }
+
+    #[must_use]
+    pub fn with_selection(mut self, selection: TextRange) -> Self {
+        let len = self.len();
+
+        self.selection = TextRange::new(selection.start.min(len), selection.end.min(len));
+
+        self
+    }
+
+    /// Number of characters in the text.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    fn byte_offset(&self, offset: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(offset)
+            .map_or(self.text.len(), |(index, _)| index)
+    }
+
+    #[must_use]
+    pub fn selected_text(&self) -> &str {
+        &self.text[self.byte_offset(self.selection.min())..self.byte_offset(self.selection.max())]
+    }
+
+    /// Returns the text before and after the selection.
+    pub(crate) fn split_at_selection(&self) -> (&str, &str) {
+        (
+            &self.text[..self.byte_offset(self.selection.min())],
+            &self.text[self.byte_offset(self.selection.max())..],
+        )
+    }
+
+    /// Replaces the selection with `text` and places the cursor after it.
+    #[must_use]
+    pub fn replace_selection(&self, text: &str) -> Self {
+        self.replace(self.selection.min(), self.selection.max(), text)
+    }
+
+    fn replace(&self, start: usize, end: usize, text: &str) -> Self {
+        let mut result = self.text.clone();
+
+        result.replace_range(self.byte_offset(start)..self.byte_offset(end), text);
+
+        Self {
+            text: result,
+            selection: TextRange::collapsed(start + text.chars().count()),
+        }
+    }
+
+    /// Deletes the selection, or the character or word before the cursor.
+    #[must_use]
+    pub fn delete_backward(&self, word: bool) -> Self {
+        if !self.selection.is_collapsed() {
+            return self.replace_selection("");
+        }
+
+        let end = self.selection.end;
+        let start = if word {
+            self.previous_word(end)
+        } else {
+            end.saturating_sub(1)
+        };
+
+        self.replace(start, end, "")
+    }
+
+    /// Deletes the selection, or the character or word after the cursor.
+    #[must_use]
+    pub fn delete_forward(&self, word: bool) -> Self {
+        if !self.selection.is_collapsed() {
+            return self.replace_selection("");
+        }
+
+        let start = self.selection.end;
+        let end = if word {
+            self.next_word(start)
+        } else {
+            (start + 1).min(self.len())
+        };
+
+        self.replace(start, end, "")
+    }
+
+    /// Moves the cursor to `offset`, keeping the anchor if `extend` is set.
+    #[must_use]
+    pub fn move_cursor(&self, offset: usize, extend: bool) -> Self {
+        let offset = offset.min(self.len());
+
+        Self {
+            text: self.text.clone(),
+            selection: if extend {
+                TextRange::new(self.selection.start, offset)
+            } else {
+                TextRange::collapsed(offset)
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn select_all(&self) -> Self {
+        Self {
+            text: self.text.clone(),
+            selection: TextRange::new(0, self.len()),
+        }
+    }
+
+    /// Returns the start of the word before `offset`.
+    #[must_use]
+    pub fn previous_word(&self, offset: usize) -> usize {
+        let chars = self.text.chars().take(offset).collect::<Vec<_>>();
+        let mut index = chars.len();
+
+        while index > 0 && !is_word_char(chars[index - 1]) {
+            index -= 1;
+        }
+
+        while index > 0 && is_word_char(chars[index - 1]) {
+            index -= 1;
+        }
+
+        index
+    }
+
+    /// Returns the end of the word after `offset`.
+    #[must_use]
+    pub fn next_word(&self, offset: usize) -> usize {
+        let mut chars = self.text.chars().skip(offset).peekable();
+        let mut index = offset;
+
+        while chars.next_if(|&char| !is_word_char(char)).is_some() {
+            index += 1;
+        }
+
+        while chars.next_if(|&char| is_word_char(char)).is_some() {
+            index += 1;
+        }
+
+        index
+    }
+
+    /// Returns the start of the line containing `offset`.
+    #[must_use]
+    pub fn line_start(&self, offset: usize) -> usize {
+        self.text
+            .chars()
+            .take(offset)
+            .collect::<Vec<_>>()
+            .iter()
+            .rposition(|&char| char == '\n')
+            .map_or(0, |index| index + 1)
+    }
+
+    /// Returns the end of the line containing `offset`, before its line
+    /// break.
+    #[must_use]
+    pub fn line_end(&self, offset: usize) -> usize {
+        self.text
+            .chars()
+            .skip(offset)
+            .position(|char| char == '\n')
+            .map_or(self.len(), |index| offset + index)
+    }
+}
+
+impl From<&str> for TextFieldValue {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for TextFieldValue {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(text: &str, start: usize, end: usize) -> TextFieldValue {
+        TextFieldValue::new(text).with_selection(TextRange::new(start, end))
+    }
+
+    #[test]
+    fn word_navigation() {
+        let value = TextFieldValue::new("foo_bar, baz  qux");
+
+        assert_eq!(value.previous_word(17), 14);
+        assert_eq!(value.previous_word(14), 9);
+        assert_eq!(value.previous_word(8), 0);
+        assert_eq!(value.previous_word(0), 0);
+        assert_eq!(value.next_word(0), 7);
+        assert_eq!(value.next_word(7), 12);
+        assert_eq!(value.next_word(12), 17);
+        assert_eq!(value.next_word(17), 17);
+    }
+
+    #[test]
+    fn line_navigation() {
+        let value = TextFieldValue::new("one\ntwo\n\nfour");
+
+        assert_eq!(value.line_start(2), 0);
+        assert_eq!(value.line_end(2), 3);
+        assert_eq!(value.line_start(3), 0);
+        assert_eq!(value.line_start(4), 4);
+        assert_eq!(value.line_end(4), 7);
+        assert_eq!(value.line_start(8), 8);
+        assert_eq!(value.line_end(8), 8);
+        assert_eq!(value.line_start(11), 9);
+        assert_eq!(value.line_end(11), 13);
+    }
+
+    #[test]
+    fn insertion() {
+        assert_eq!(
+            value("héllo", 1, 1).replace_selection("ab"),
+            value("habéllo", 3, 3)
+        );
+        assert_eq!(
+            value("héllo", 4, 1).replace_selection("é"),
+            value("héo", 2, 2)
+        );
+        assert_eq!(value("héllo", 0, 5).split_at_selection(), ("", ""));
+        assert_eq!(value("héllo", 3, 1).split_at_selection(), ("h", "lo"));
+        assert_eq!(value("héllo", 3, 1).selected_text(), "él");
+    }
+
+    #[test]
+    fn deletion() {
+        assert_eq!(
+            value("héllo", 2, 2).delete_backward(false),
+            value("hllo", 1, 1)
+        );
+        assert_eq!(
+            value("héllo", 0, 0).delete_backward(false),
+            value("héllo", 0, 0)
+        );
+        assert_eq!(
+            value("héllo", 2, 2).delete_forward(false),
+            value("hélo", 2, 2)
+        );
+        assert_eq!(
+            value("héllo", 5, 5).delete_forward(false),
+            value("héllo", 5, 5)
+        );
+        assert_eq!(
+            value("héllo", 1, 4).delete_forward(false),
+            value("ho", 1, 1)
+        );
+        assert_eq!(
+            value("foo bar", 7, 7).delete_backward(true),
+            value("foo ", 4, 4)
+        );
+        assert_eq!(
+            value("foo bar", 3, 3).delete_forward(true),
+            value("foo", 3, 3)
+        );
+    }
+
+    #[test]
+    fn selection_is_clamped() {
+        let value = TextFieldValue::new("abc");
+
+        assert_eq!(value.selection, TextRange::collapsed(3));
+        assert_eq!(
+            value.with_selection(TextRange::new(1, 10)).selection,
+            TextRange::new(1, 3)
+        );
+        assert_eq!(
+            value.move_cursor(10, false).selection,
+            TextRange::collapsed(3)
+        );
+        assert_eq!(
+            value.move_cursor(1, false).move_cursor(2, true).selection,
+            TextRange::new(1, 2)
+        );
+    }
+}
@@ -0,0 +1,508 @@
+use std::{
+    cell::{OnceCell, RefCell},
+    fs,
+    path::PathBuf,
+    rc::Rc,
+};
+
+use rumpose_core::prelude::*;
+
+use crate::{Brush, ImageProps, ModifierElement, image::measure_intrinsic, modified};
+
+/// Path built from SVG-like commands, usable in vectors and in
+/// [`DrawScope`](crate::DrawScope).
+#[derive(Debug, Clone, Default)]
+pub struct PathData(pub(crate) Path);
+
+impl PathData {
+    #[must_use]
+    pub fn build(build: impl FnOnce(PathDataBuilder) -> PathDataBuilder) -> Self {
+        Self(build(PathDataBuilder::new()).path)
+    }
+
+    /// Parses SVG path data (the `d` attribute).
+    #[must_use]
+    pub fn from_svg(data: &str) -> Option<Self> {
+        Path::from_svg(data).map(Self)
+    }
+
+    #[must_use]
+    pub fn bounds(&self) -> Rect2D {
+        let bounds = self.0.bounds();
+
+        Rect2D::from_xywh(bounds.left, bounds.top, bounds.width(), bounds.height())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct VectorPath {
+    path: Path,
+    fill: Option<Brush>,
+    stroke: Option<(Brush, f32)>,
+}
+
+/// Vector graphic defined in code, drawn by scaling its viewport to the
+/// target area.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageVector {
+    default_size: Size2D,
+    viewport: Size2D,
+    paths: Rc<[VectorPath]>,
+}
+
+impl ImageVector {
+    /// Starts building a vector with the given default size, which is also
+    /// used as its viewport unless [`ImageVectorBuilder::viewport`] is set.
+    #[must_use]
+    pub fn builder(width: f32, height: f32) -> ImageVectorBuilder {
+        ImageVectorBuilder {
+            default_size: Size2D::new(width, height),
+            viewport: Size2D::new(width, height),
+            paths: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn default_size(&self) -> Size2D {
+        self.default_size
+    }
+
+    #[must_use]
+    pub const fn viewport(&self) -> Size2D {
+        self.viewport
+    }
+
+    /// Draws the vector scaled to fill `area`.
+    pub fn render(&self, canvas: &Canvas, area: Rect2D) {
+        let viewport = Rect2D::new(Point2D::default(), self.viewport);
+
+        canvas.save();
+        canvas.translate(Point::from(area.origin));
+        canvas.scale((
+            area.size.width / self.viewport.width,
+            area.size.height / self.viewport.height,
+        ));
+
+        for VectorPath { path, fill, stroke } in self.paths.iter() {
+            if let Some(brush) = fill {
+                canvas.draw_path(path, &brush.to_paint(viewport));
+            }
+
+            if let Some((brush, width)) = stroke {
+                let mut paint = brush.to_paint(viewport);
+
+                paint.set_stroke(true);
+                paint.set_stroke_width(*width);
+
+                canvas.draw_path(path, &paint);
+            }
+        }
+
+        canvas.restore();
+    }
+}
+
+pub struct ImageVectorBuilder {
+    default_size: Size2D,
+    viewport: Size2D,
+    paths: Vec<VectorPath>,
+}
+
+impl ImageVectorBuilder {
+    #[must_use]
+    pub const fn viewport(mut self, width: f32, height: f32) -> Self {
+        self.viewport = Size2D::new(width, height);
+
+        self
+    }
+
+    #[must_use]
+    pub fn path(
+        mut self,
+        fill: impl Into<Brush>,
+        build: impl FnOnce(PathDataBuilder) -> PathDataBuilder,
+    ) -> Self {
+        self.paths.push(VectorPath {
+            path: PathData::build(build).0,
+            fill: Some(fill.into()),
+            stroke: None,
+        });
+
+        self
+    }
+
+    #[must_use]
+    pub fn stroked_path(
+        mut self,
+        stroke: impl Into<Brush>,
+        width: f32,
+        build: impl FnOnce(PathDataBuilder) -> PathDataBuilder,
+    ) -> Self {
+        self.paths.push(VectorPath {
+            path: PathData::build(build).0,
+            fill: None,
+            stroke: Some((stroke.into(), width)),
+        });
+
+        self
+    }
+
+    /// Adds a filled path from SVG path data (the `d` attribute).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is not valid SVG path data.
+    #[must_use]
+    #[track_caller]
+    pub fn svg_path(mut self, fill: impl Into<Brush>, data: &str) -> Self {
+        self.paths.push(VectorPath {
+            path: PathData::from_svg(data).expect("invalid SVG path data").0,
+            fill: Some(fill.into()),
+            stroke: None,
+        });
+
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> ImageVector {
+        ImageVector {
+            default_size: self.default_size,
+            viewport: self.viewport,
+            paths: self.paths.into(),
+        }
+    }
+}
+
+/// Builds a path in viewport coordinates, mirroring the SVG path commands.
+pub struct PathDataBuilder {
+    path: Path,
+    current: Point2D,
+    start: Point2D,
+}
+
+impl PathDataBuilder {
+    fn new() -> Self {
+        Self {
+            path: Path::new(),
+            current: Point2D::default(),
+            start: Point2D::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn move_to(mut self, x: f32, y: f32) -> Self {
+        self.current = Point2D::new(x, y);
+        self.start = self.current;
+        self.path.move_to(Point::from(self.current));
+
+        self
+    }
+
+    #[must_use]
+    pub fn move_to_relative(self, dx: f32, dy: f32) -> Self {
+        let Point2D { x, y } = self.current;
+
+        self.move_to(x + dx, y + dy)
+    }
+
+    #[must_use]
+    pub fn line_to(mut self, x: f32, y: f32) -> Self {
+        self.current = Point2D::new(x, y);
+        self.path.line_to(Point::from(self.current));
+
+        self
+    }
+
+    #[must_use]
+    pub fn line_to_relative(self, dx: f32, dy: f32) -> Self {
+        let Point2D { x, y } = self.current;
+
+        self.line_to(x + dx, y + dy)
+    }
+
+    #[must_use]
+    pub fn horizontal_line_to(self, x: f32) -> Self {
+        let y = self.current.y;
+
+        self.line_to(x, y)
+    }
+
+    #[must_use]
+    pub fn horizontal_line_to_relative(self, dx: f32) -> Self {
+        self.line_to_relative(dx, 0.)
+    }
+
+    #[must_use]
+    pub fn vertical_line_to(self, y: f32) -> Self {
+        let x = self.current.x;
+
+        self.line_to(x, y)
+    }
+
+    #[must_use]
+    pub fn vertical_line_to_relative(self, dy: f32) -> Self {
+        self.line_to_relative(0., dy)
+    }
+
+    #[must_use]
+    pub fn quad_to(mut self, x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        self.current = Point2D::new(x2, y2);
+        self.path
+            .quad_to(Point::new(x1, y1), Point::from(self.current));
+
+        self
+    }
+
+    #[must_use]
+    pub fn quad_to_relative(self, dx1: f32, dy1: f32, dx2: f32, dy2: f32) -> Self {
+        let Point2D { x, y } = self.current;
+
+        self.quad_to(x + dx1, y + dy1, x + dx2, y + dy2)
+    }
+
+    #[must_use]
+    pub fn curve_to(mut self, x1: f32, y1: f32, x2: f32, y2: f32, x3: f32, y3: f32) -> Self {
+        self.current = Point2D::new(x3, y3);
+        self.path.cubic_to(
+            Point::new(x1, y1),
+            Point::new(x2, y2),
+            Point::from(self.current),
+        );
+
+        self
+    }
+
+    #[must_use]
+    pub fn curve_to_relative(
+        self,
+        dx1: f32,
+        dy1: f32,
+        dx2: f32,
+        dy2: f32,
+        dx3: f32,
+        dy3: f32,
+    ) -> Self {
+        let Point2D { x, y } = self.current;
+
+        self.curve_to(x + dx1, y + dy1, x + dx2, y + dy2, x + dx3, y + dy3)
+    }
+
+    #[must_use]
+    pub fn close(mut self) -> Self {
+        self.current = self.start;
+        self.path.close();
+
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VectorSource {
+    /// SVG document contents.
+    Svg(Rc<[u8]>),
+    SvgFile(PathBuf),
+    Vector(ImageVector),
+}
+
+impl VectorSource {
+    #[must_use]
+    pub fn svg(value: impl Into<Rc<[u8]>>) -> Self {
+        Self::Svg(value.into())
+    }
+
+    #[must_use]
+    pub fn svg_file(path: impl Into<PathBuf>) -> Self {
+        Self::SvgFile(path.into())
+    }
+
+    /// Loads the source, resolving the fonts of SVG text through `fonts`.
+    fn load(&self, fonts: &FontRegistry) -> Option<LoadedVector> {
+        let dom = match self {
+            Self::Svg(bytes) => SvgDom::from_bytes(bytes, fonts.font_manager()).ok()?,
+            Self::SvgFile(path) => {
+                SvgDom::from_bytes(&fs::read(path).ok()?, fonts.font_manager()).ok()?
+            }
+            Self::Vector(vector) => return Some(LoadedVector::Vector(vector.clone())),
+        };
+
+        let root = dom.root();
+        let size = root.intrinsic_size();
+
+        let size = if size.is_empty() {
+            root.view_box()
+                .map(|view_box| view_box.size())
+                .unwrap_or_default()
+        } else {
+            size
+        };
+
+        Some(LoadedVector::Svg(dom, Size2D::new(size.width, size.height)))
+    }
+}
+
+impl From<ImageVector> for VectorSource {
+    fn from(value: ImageVector) -> Self {
+        Self::Vector(value)
+    }
+}
+
+/// Returns `true` for content without a usable size, e.g. an SVG with neither
+/// a size nor a viewBox.
+fn is_unsized(size: Size2D) -> bool {
+    size.width <= 0. || size.height <= 0.
+}
+
+/// Measures unsized content by filling the bounded constraints.
+fn measure_unsized(constraints: Constraints) -> Size2D {
+    let fill = |min: f32, max: f32| if max.is_finite() { max } else { min };
+
+    Size2D::new(
+        fill(constraints.min.width, constraints.max.width),
+        fill(constraints.min.height, constraints.max.height),
+    )
+}
+
+#[derive(Clone)]
+enum LoadedVector {
+    Svg(SvgDom, Size2D),
+    Vector(ImageVector),
+}
+
+impl LoadedVector {
+    const fn intrinsic_size(&self) -> Size2D {
+        match self {
+            Self::Svg(_, size) => *size,
+            Self::Vector(vector) => vector.default_size,
+        }
+    }
+
+    fn render(&self, canvas: &Canvas, area: Rect2D) {
+        match self {
+            Self::Svg(dom, size) => {
+                let mut dom = dom.clone();
+
+                canvas.save();
+                canvas.translate(Point::from(area.origin));
+
+                // Unsized documents are laid out in the area instead of being
+                // scaled to it.
+                if is_unsized(*size) {
+                    dom.set_container_size(Size::from(area.size));
+                } else {
+                    canvas.scale((area.size.width / size.width, area.size.height / size.height));
+                    dom.set_container_size(Size::from(*size));
+                }
+
+                dom.render(canvas);
+
+                canvas.restore();
+            }
+            Self::Vector(vector) => vector.render(canvas, area),
+        }
+    }
+}
+
+#[track_caller]
+pub fn vector_image(
+    scope: Scope,
+    modifier: impl ModifierElement,
+    source: impl Into<VectorSource>,
+    props: ImageProps,
+) {
+    let source = source.into();
+
+    modified(scope, &modifier, move |scope| {
+        // Loaded on first use, as SVG text needs the fonts of the context,
+        // and kept across recompositions until the source changes.
+        let cache = scope
+            .use_state(|| {
+                Rc::new(RefCell::new(
+                    None::<(VectorSource, Rc<OnceCell<Option<LoadedVector>>>)>,
+                ))
+            })
+            .with(Clone::clone);
+        let vector = {
+            let mut cache = cache.borrow_mut();
+
+            match &*cache {
+                Some((key, vector)) if *key == source => vector.clone(),
+                _ => {
+                    let vector = Rc::new(OnceCell::new());
+
+                    *cache = Some((source.clone(), vector.clone()));
+
+                    vector
+                }
+            }
+        };
+        let load = {
+            let source = source.clone();
+
+            move |fonts: &FontRegistry| vector.get_or_init(|| source.load(fonts)).clone()
+        };
+        let measure_load = load.clone();
+        let props = props.clone();
+
+        draw(
+            scope,
+            move |context| {
+                let Some(vector) = load(context.fonts()) else {
+                    return;
+                };
+
+                let intrinsic = vector.intrinsic_size();
+                let area = context.area();
+                let paint = props.paint();
+
+                context.with_canvas(|canvas| {
+                    canvas.save();
+                    canvas.clip_rect(Rect::from(area), None, Some(true));
+                    canvas.save_layer(&SaveLayerRec::default().paint(&paint));
+
+                    let target = if is_unsized(intrinsic) {
+                        area
+                    } else {
+                        props.place(intrinsic, area)
+                    };
+
+                    vector.render(canvas, target);
+
+                    canvas.restore();
+                    canvas.restore();
+                });
+            },
+            move |scope| {
+                let load = measure_load.clone();
+
+                layout(
+                    scope,
+                    move |_, context, constraints| {
+                        let intrinsic = load(context.fonts())
+                            .as_ref()
+                            .map(LoadedVector::intrinsic_size)
+                            .unwrap_or_default();
+
+                        if is_unsized(intrinsic) {
+                            measure_unsized(constraints)
+                        } else {
+                            measure_intrinsic(intrinsic, constraints)
+                        }
+                    },
+                    |_| {},
+                );
+            },
+        );
+    });
+}
+
+/// Draws a vector tinted with a single color.
+#[track_caller]
+pub fn icon(
+    scope: Scope,
+    modifier: impl ModifierElement,
+    source: impl Into<VectorSource>,
+    tint: Color,
+) {
+    vector_image(scope, modifier, source, ImageProps::new().tint(tint));
+}
@@ -0,0 +1,110 @@
+use std::rc::Rc;
+
+use crate::{
+    Scope,
+    input::{KeyEvent, PointerEvent, PointerEventPass},
+    node::{
+        FocusPhase, FocusProps, KeyHandler, KeyInputPhase, Node, NodePhase, PointerHandler,
+        PointerInputPhase,
+    },
+};
+
+/// Passes key events to `on_key` while the focused
+/// [`focus_target`](focus_target) is `content`, inside it, or the node the key
+/// input node wraps. Events reach the nodes on the way from the focused one to
+/// the root, deepest first, until one of them returns `true`.
+#[track_caller]
+pub fn key_input<H: Fn(&KeyEvent) -> bool + Clone + 'static, C: Fn(Scope) + Clone + 'static>(
+    scope: Scope,
+    on_key: H,
+    content: C,
+) {
+    key_input_node(scope, false, on_key, content);
+}
+
+/// Like [`key_input`], but the nodes receive the events before the ones of
+/// `key_input`, from the root down, e.g. to intercept the keys of a text
+/// field.
+#[track_caller]
+pub fn preview_key_input<
+    H: Fn(&KeyEvent) -> bool + Clone + 'static,
+    C: Fn(Scope) + Clone + 'static,
+>(
+    scope: Scope,
+    on_key: H,
+    content: C,
+) {
+    key_input_node(scope, true, on_key, content);
+}
+
+#[track_caller]
+fn key_input_node<H: Fn(&KeyEvent) -> bool + Clone + 'static, C: Fn(Scope) + Clone + 'static>(
+    scope: Scope,
+    preview: bool,
+    on_key: H,
+    content: C,
+) {
+    let child_scope = scope.child();
+
+    scope.create_node(
+        child_scope,
+        content,
+        move || on_key.clone(),
+        move |on_key, _| Node::new(NodePhase::KeyInput(KeyInputPhase::new(on_key, preview))),
+        move |node, on_key, _| {
+            if let NodePhase::KeyInput(phase) = &mut node.phase {
+                let handler: KeyHandler = Rc::new(on_key);
+
+                phase.handler = handler;
+            }
+        },
+    );
+}
+
+/// Makes `content` focusable, see [`FocusManager`](crate::input::FocusManager).
+/// The focus is kept across recompositions and lost when the node is disposed.
+#[track_caller]
+pub fn focus_target<C: Fn(Scope) + Clone + 'static>(scope: Scope, props: FocusProps, content: C) {
+    let child_scope = scope.child();
+
+    scope.create_node(
+        child_scope,
+        content,
+        move || props.clone(),
+        move |props, _| Node::new(NodePhase::Focus(FocusPhase::new(&props))),
+        move |node, props, _| {
+            if let NodePhase::Focus(phase) = &node.phase {
+                phase.update(&props);
+            }
+        },
+    );
+}
+
+/// Passes the pointer events hitting `content` to `on_event`, once per
+/// [`PointerEventPass`]. The node is hit where its area, clipped and
+/// transformed by the enclosing layers, contains the pointer.
+#[track_caller]
+pub fn pointer_input<
+    H: Fn(&PointerEvent, PointerEventPass) + Clone + 'static,
+    C: Fn(Scope) + Clone + 'static,
+>(
+    scope: Scope,
+    on_event: H,
+    content: C,
+) {
+    let child_scope = scope.child();
+
+    scope.create_node(
+        child_scope,
+        content,
+        move || on_event.clone(),
+        move |on_event, _| Node::new(NodePhase::PointerInput(PointerInputPhase::new(on_event))),
+        move |node, on_event, _| {
+            if let NodePhase::PointerInput(phase) = &mut node.phase {
+                let handler: PointerHandler = Rc::new(on_event);
+
+                phase.set_handler(handler);
+            }
+        },
+    );
+}
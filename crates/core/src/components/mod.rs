@@ -1,7 +1,9 @@
+mod input;
 mod layout;
 mod render;
 
 pub use self::{
+    input::{focus_target, key_input, pointer_input, preview_key_input},
     layout::{layout, subcompose_layout},
-    render::draw,
+    render::{draw, layer},
 };
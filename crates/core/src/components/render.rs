@@ -1,7 +1,9 @@
 use std::rc::Rc;
 
 use crate::{
-    node::{Draw, Node, NodePhase, RenderPhase}, phase::RenderContext, Scope
+    Scope,
+    node::{Draw, LayerPhase, LayerProps, Node, NodePhase, RenderPhase},
+    phase::RenderContext,
 };
 
 #[track_caller]
@@ -27,7 +29,27 @@ pub fn draw<D: Fn(&RenderContext) + Clone + 'static, C: Fn(Scope) + Clone + 'sta
                     node.mark_render_dirty();
                 }
             }
+        },
+    );
+}
+
+/// Draws `content` with the transform and clip of `props`, ordered among its
+/// siblings by its z-index. Pointer events are hit tested the same way.
+#[track_caller]
+pub fn layer<C: Fn(Scope) + Clone + 'static>(scope: Scope, props: LayerProps, content: C) {
+    let child_scope = scope.child();
 
+    scope.create_node(
+        child_scope,
+        content,
+        move || props.clone(),
+        move |props, _| Node::new(NodePhase::Layer(LayerPhase::new(props))),
+        move |node, props, _| {
+            if let NodePhase::Layer(phase) = &mut node.phase {
+                phase.props = props;
+
+                node.mark_render_dirty();
+            }
         },
     );
 }
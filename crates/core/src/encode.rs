@@ -0,0 +1,157 @@
+use std::{error::Error, fmt};
+
+use rumpose_engine::{AlphaType, ColorType, EncodedImageFormat, IRect, Image, ImageInfo};
+use rumpose_layout::Rect2D;
+
+use crate::RumposeContext;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl From<ImageFormat> for EncodedImageFormat {
+    fn from(value: ImageFormat) -> Self {
+        match value {
+            ImageFormat::Png => Self::PNG,
+            ImageFormat::Jpeg => Self::JPEG,
+            ImageFormat::Webp => Self::WEBP,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The requested region is empty or lies outside of the surface.
+    InvalidRegion,
+    /// Skia failed to read or encode the pixels.
+    Failed(ImageFormat),
+    /// Skia failed to read the pixels as raw RGBA.
+    ReadFailed,
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRegion => f.write_str("region is empty or outside of the surface"),
+            Self::Failed(format) => write!(f, "failed to encode surface as {format:?}"),
+            Self::ReadFailed => f.write_str("failed to read surface pixels"),
+        }
+    }
+}
+
+impl Error for EncodeError {}
+
+impl RumposeContext {
+    fn region(&self, region: Rect2D) -> Result<IRect, EncodeError> {
+        let surface = self.surface.borrow();
+        let region = IRect::from_xywh(
+            region.origin.x.floor() as i32,
+            region.origin.y.floor() as i32,
+            region.size.width.ceil() as i32,
+            region.size.height.ceil() as i32,
+        );
+
+        if region.is_empty()
+            || region.left < 0
+            || region.top < 0
+            || region.right > surface.width()
+            || region.bottom > surface.height()
+        {
+            Err(EncodeError::InvalidRegion)
+        } else {
+            Ok(region)
+        }
+    }
+
+    fn encode_image(
+        image: &Image,
+        format: ImageFormat,
+        quality: u8,
+    ) -> Result<Vec<u8>, EncodeError> {
+        image
+            .encode(None, format.into(), u32::from(quality.min(100)))
+            .map(|data| data.to_vec())
+            .ok_or(EncodeError::Failed(format))
+    }
+
+    /// Encodes the whole surface. `quality` ranges from 0 to 100 and is
+    /// ignored by lossless formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodeError::Failed`] if skia cannot encode the image.
+    pub fn encode_as(&self, format: ImageFormat, quality: u8) -> Result<Vec<u8>, EncodeError> {
+        Self::encode_image(&self.snapshot(), format, quality)
+    }
+
+    /// Encodes the part of the surface covered by `region`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodeError::InvalidRegion`] if `region` is empty or not fully
+    /// inside the surface, and [`EncodeError::Failed`] if encoding fails.
+    pub fn encode_region_as(
+        &self,
+        region: Rect2D,
+        format: ImageFormat,
+        quality: u8,
+    ) -> Result<Vec<u8>, EncodeError> {
+        let region = self.region(region)?;
+        let image = self
+            .surface
+            .borrow_mut()
+            .image_snapshot_with_bounds(region)
+            .ok_or(EncodeError::Failed(format))?;
+
+        Self::encode_image(&image, format, quality)
+    }
+
+    /// Returns unpremultiplied RGBA pixels of the whole surface, row by row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodeError::ReadFailed`] if skia cannot read the pixels.
+    pub fn read_rgba(&self) -> Result<Vec<u8>, EncodeError> {
+        let (width, height) = {
+            let surface = self.surface.borrow();
+
+            (surface.width(), surface.height())
+        };
+
+        self.read_rgba_region(Rect2D::from_xywh(0., 0., width as f32, height as f32))
+    }
+
+    /// Returns unpremultiplied RGBA pixels of `region`, row by row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodeError::InvalidRegion`] if `region` is empty or not fully
+    /// inside the surface, and [`EncodeError::ReadFailed`] if skia cannot read
+    /// the pixels.
+    pub fn read_rgba_region(&self, region: Rect2D) -> Result<Vec<u8>, EncodeError> {
+        let region = self.region(region)?;
+        let info = ImageInfo::new(
+            region.size(),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+
+        let row_bytes = info.min_row_bytes();
+        let mut pixels = vec![0; row_bytes * region.height() as usize];
+
+        if self.surface.borrow_mut().read_pixels(
+            &info,
+            &mut pixels,
+            row_bytes,
+            (region.left, region.top),
+        ) {
+            Ok(pixels)
+        } else {
+            Err(EncodeError::ReadFailed)
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use std::{error::Error, fmt, fs, io, iter, path::Path};
+
+use rumpose_engine::{
+    FontArguments, FontCollection, FontManager, FourByteTag, Typeface, TypefaceFontProvider,
+    VariationCoordinate, VariationPosition,
+};
+
+#[derive(Debug)]
+pub enum FontError {
+    Io(io::Error),
+    /// The data is not a font skia can read.
+    InvalidData,
+    /// A variation axis tag is not four ASCII characters long.
+    InvalidAxis(String),
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read font: {error}"),
+            Self::InvalidData => f.write_str("invalid font data"),
+            Self::InvalidAxis(tag) => write!(f, "invalid variation axis tag {tag:?}"),
+        }
+    }
+}
+
+impl Error for FontError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FontError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+/// Fonts available to text, resolved by family name. Registered fonts take
+/// precedence over the system fonts, which are used for the default family
+/// and fallbacks otherwise.
+///
+/// With the `bundled-font` feature, the embedded
+/// [`BUNDLED_FONT_FAMILY`](rumpose_engine::BUNDLED_FONT_FAMILY) is the
+/// default family and system fonts are disabled, so rendering does not depend
+/// on the fonts installed on the host.
+///
+/// Clones share the registered fonts, so registering through the root
+/// context also affects its subcompositions. Fonts should be registered
+/// before composing, as text already laid out is not measured again.
+#[derive(Clone)]
+pub struct FontRegistry {
+    provider: TypefaceFontProvider,
+    collection: FontCollection,
+    system: FontManager,
+    system_fonts: bool,
+    default_family: String,
+    fallback_families: Vec<String>,
+}
+
+impl FontRegistry {
+    pub(crate) fn new() -> Self {
+        let mut registry = Self {
+            provider: TypefaceFontProvider::new(),
+            collection: FontCollection::new(),
+            system: FontManager::new(),
+            system_fonts: !cfg!(feature = "bundled-font"),
+            default_family: "Arial".to_owned(),
+            fallback_families: Vec::new(),
+        };
+
+        #[cfg(feature = "bundled-font")]
+        {
+            registry.default_family = rumpose_engine::BUNDLED_FONT_FAMILY.to_owned();
+            registry
+                .register(
+                    rumpose_engine::BUNDLED_FONT_FAMILY,
+                    rumpose_engine::BUNDLED_FONT,
+                )
+                .expect("bundled font is invalid");
+        }
+
+        registry.update();
+
+        registry
+    }
+
+    fn update(&mut self) {
+        let families = iter::once(&self.default_family)
+            .chain(&self.fallback_families)
+            .collect::<Vec<_>>();
+
+        self.collection
+            .set_asset_font_manager(Some(FontManager::from(self.provider.clone())));
+        self.collection.set_default_font_manager_and_family_names(
+            self.system_fonts.then(|| self.system.clone()),
+            &families,
+        );
+
+        if self.system_fonts {
+            self.collection.enable_font_fallback();
+        } else {
+            self.collection.disable_font_fallback();
+        }
+
+        self.collection.clear_caches();
+    }
+
+    /// Parses font data into a typeface. Skia only reads font data through a
+    /// platform font manager, so the system one parses it even when system
+    /// fonts are disabled; the typeface is looked up through the registered
+    /// fonts only.
+    fn parse(&self, data: &[u8]) -> Result<Typeface, FontError> {
+        self.system
+            .new_from_data(data, None)
+            .ok_or(FontError::InvalidData)
+    }
+
+    fn add(&mut self, family: &str, typeface: Typeface) {
+        self.provider.register_typeface(typeface, Some(family));
+        self.update();
+    }
+
+    /// Registers a TrueType or OpenType font under `family`. Fonts of
+    /// different weights and slants registered under the same family are
+    /// matched by the requested style.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FontError::InvalidData`] if the data is not a font.
+    pub fn register(&mut self, family: &str, data: &[u8]) -> Result<(), FontError> {
+        let typeface = self.parse(data)?;
+
+        self.add(family, typeface);
+
+        Ok(())
+    }
+
+    /// Reads and registers a font file, see
+    /// [`register`](FontRegistry::register).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a font.
+    pub fn register_file(&mut self, family: &str, path: impl AsRef<Path>) -> Result<(), FontError> {
+        self.register(family, &fs::read(path)?)
+    }
+
+    /// Registers an instance of a variable font under `family`, with each axis
+    /// given by its tag and value, e.g. `("wght", 650.)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not a font or an axis tag is invalid.
+    pub fn register_variation(
+        &mut self,
+        family: &str,
+        data: &[u8],
+        axes: &[(&str, f32)],
+    ) -> Result<(), FontError> {
+        let coordinates = axes
+            .iter()
+            .map(|&(tag, value)| match tag.as_bytes() {
+                &[a, b, c, d] if tag.is_ascii() => Ok(VariationCoordinate {
+                    axis: FourByteTag::from_chars(a as char, b as char, c as char, d as char),
+                    value,
+                }),
+                _ => Err(FontError::InvalidAxis(tag.to_owned())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let typeface = self
+            .parse(data)?
+            .clone_with_arguments(&FontArguments::new().set_variation_design_position(
+                VariationPosition {
+                    coordinates: &coordinates,
+                },
+            ))
+            .ok_or(FontError::InvalidData)?;
+
+        self.add(family, typeface);
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub const fn system_fonts(&self) -> bool {
+        self.system_fonts
+    }
+
+    /// Enables looking up families, including the default and fallback ones,
+    /// among the fonts installed on the system. Without them, only registered
+    /// fonts are used and characters they lack are not drawn.
+    pub fn set_system_fonts(&mut self, enabled: bool) {
+        self.system_fonts = enabled;
+        self.update();
+    }
+
+    #[must_use]
+    pub fn default_family(&self) -> &str {
+        &self.default_family
+    }
+
+    /// Sets the family used by text without an explicit family.
+    pub fn set_default_family(&mut self, family: impl Into<String>) {
+        self.default_family = family.into();
+        self.update();
+    }
+
+    #[must_use]
+    pub fn fallback_families(&self) -> &[String] {
+        &self.fallback_families
+    }
+
+    /// Sets the families tried in order for characters missing from the
+    /// requested family.
+    pub fn set_fallback_families(&mut self, families: impl IntoIterator<Item = impl Into<String>>) {
+        self.fallback_families = families.into_iter().map(Into::into).collect();
+        self.update();
+    }
+
+    #[must_use]
+    pub const fn collection(&self) -> &FontCollection {
+        &self.collection
+    }
+
+    /// Returns a font manager for content looking fonts up by itself, such as
+    /// SVG documents: the registered fonts, or the system fonts if they are
+    /// enabled and no font is registered.
+    #[must_use]
+    pub fn font_manager(&self) -> FontManager {
+        if self.system_fonts && self.provider.count_families() == 0 {
+            self.system.clone()
+        } else {
+            FontManager::from(self.provider.clone())
+        }
+    }
+}
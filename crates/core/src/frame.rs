@@ -0,0 +1,34 @@
+use std::{cell::RefCell, time::Duration};
+
+type FrameCallback = Box<dyn FnOnce(Duration)>;
+
+thread_local! {
+    /// Callbacks waiting for the next frame. Like states, they are not tied to
+    /// a composer, so pointer handlers and other callbacks can request frames.
+    static REQUESTS: RefCell<Vec<FrameCallback>> = RefCell::default();
+}
+
+/// Runs `callback` with the frame time on the next
+/// [`ComposerExt::advance_frame`](crate::ComposerExt::advance_frame), e.g. to
+/// move an animation by one step. Callbacks requesting another frame from
+/// inside run on the following one.
+pub fn request_frame<F: FnOnce(Duration) + 'static>(callback: F) {
+    REQUESTS.with_borrow_mut(|requests| requests.push(Box::new(callback)));
+}
+
+/// Returns `true` if a callback is waiting for the next frame.
+#[must_use]
+pub fn has_frame_requests() -> bool {
+    REQUESTS.with_borrow(|requests| !requests.is_empty())
+}
+
+pub(crate) fn run_frame(time: Duration) -> bool {
+    let requests = REQUESTS.take();
+    let ran = !requests.is_empty();
+
+    for callback in requests {
+        callback(time);
+    }
+
+    ran
+}
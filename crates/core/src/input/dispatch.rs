@@ -0,0 +1,206 @@
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
+
+use rumpose_engine::{Matrix, Point};
+use rumpose_layout::Point2D;
+
+use super::{PointerEvent, PointerEventPass, PointerEventType, PointerId, PointerType};
+use crate::{
+    Composer, ComposerExt, draw_order,
+    node::{NodePhase, PointerTarget},
+};
+
+thread_local! {
+    /// Number of the last dispatch, shared by the composers of the thread so
+    /// subcompositions never reuse one.
+    static DISPATCH: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Targets of the pressed and hovering pointers, kept by the root context.
+#[derive(Default)]
+pub(crate) struct PointerTracker {
+    pressed: HashMap<PointerId, Vec<Weak<PointerTarget>>>,
+    hovered: HashMap<PointerId, Vec<Weak<PointerTarget>>>,
+}
+
+fn downgrade(targets: &[Rc<PointerTarget>]) -> Vec<Weak<PointerTarget>> {
+    targets.iter().map(Rc::downgrade).collect()
+}
+
+fn upgrade(targets: &[Weak<PointerTarget>]) -> Vec<Rc<PointerTarget>> {
+    targets.iter().filter_map(Weak::upgrade).collect()
+}
+
+/// Collects the pointer input targets of the subtree under `position`,
+/// outermost first, and returns `true` if any was hit. `position` and
+/// `to_local` are in the coordinates of the parent of the node.
+pub(crate) fn hit_test(
+    composer: &Composer,
+    id: usize,
+    mut position: Point2D,
+    mut to_local: Matrix,
+    hits: &mut Vec<Rc<PointerTarget>>,
+) -> bool {
+    let node = &composer.nodes[id];
+    let mut hit = false;
+
+    if let Some(data) = node.data.as_ref() {
+        let area = *data.area.borrow();
+
+        match &data.phase {
+            NodePhase::Layer(phase) => {
+                let Some(inverse) = phase.props.matrix(area).invert() else {
+                    return false;
+                };
+
+                position = Point2D::from(inverse.map_point(Point::from(position)));
+                to_local = Matrix::concat(&inverse, &to_local);
+
+                if let Some(clip) = &phase.props.clip {
+                    if !clip(area).contains(Point::from(position)) {
+                        return false;
+                    }
+                }
+            }
+            NodePhase::PointerInput(phase) => {
+                if !area.contains(&position) {
+                    return false;
+                }
+
+                phase.target.update(to_local, area);
+                hits.push(phase.target.clone());
+
+                hit = true;
+            }
+            NodePhase::MeasurementCompose(phase) => {
+                return phase.context.hit_test(position, to_local, hits);
+            }
+            _ => {}
+        }
+    }
+
+    draw_order(composer, node)
+        .into_iter()
+        .rev()
+        .any(|id| hit_test(composer, id, position, to_local, hits))
+        || hit
+}
+
+/// Passes `event` through the passes of `targets`, outermost first.
+fn deliver(targets: &[Rc<PointerTarget>], event: &PointerEvent) {
+    for target in targets {
+        target.deliver(event, PointerEventPass::Initial);
+    }
+
+    for target in targets.iter().rev() {
+        target.deliver(event, PointerEventPass::Main);
+    }
+
+    for target in targets {
+        target.deliver(event, PointerEventPass::Final);
+    }
+}
+
+/// Sends [`Exit`](PointerEventType::Exit) and
+/// [`Enter`](PointerEventType::Enter) events to the targets a hovering
+/// pointer left and entered.
+fn update_hover(composer: &Composer, event: &PointerEvent, hovered: &[Rc<PointerTarget>]) {
+    let previous = composer
+        .context
+        .pointers
+        .borrow_mut()
+        .hovered
+        .insert(event.id, downgrade(hovered))
+        .map(|targets| upgrade(&targets))
+        .unwrap_or_default();
+
+    let exit = event.with_kind(PointerEventType::Exit);
+
+    for target in previous
+        .iter()
+        .filter(|target| !hovered.iter().any(|item| Rc::ptr_eq(item, target)))
+    {
+        deliver(std::slice::from_ref(target), &exit);
+    }
+
+    let enter = event.with_kind(PointerEventType::Enter);
+
+    for target in hovered
+        .iter()
+        .filter(|target| !previous.iter().any(|item| Rc::ptr_eq(item, target)))
+    {
+        deliver(std::slice::from_ref(target), &enter);
+    }
+}
+
+pub(crate) fn dispatch_pointer(composer: &Composer, mut event: PointerEvent) -> bool {
+    let hit = || {
+        let mut hits = Vec::new();
+
+        hit_test(
+            composer,
+            composer.root_node_key(),
+            event.position,
+            Matrix::new_identity(),
+            &mut hits,
+        );
+
+        hits
+    };
+
+    // Pressed pointers stay with the targets they were pressed on.
+    let pressed = {
+        let mut pointers = composer.context.pointers.borrow_mut();
+
+        match event.kind {
+            PointerEventType::Release | PointerEventType::Cancel => {
+                pointers.pressed.remove(&event.id)
+            }
+            _ => pointers.pressed.get(&event.id).cloned(),
+        }
+        .map(|targets| upgrade(&targets))
+    };
+
+    event.density = composer.context.density();
+    event.dispatch = DISPATCH.with(|dispatch| {
+        dispatch.set(dispatch.get() + 1);
+
+        dispatch.get()
+    });
+    event.pressed = event.kind == PointerEventType::Press
+        || (event.kind == PointerEventType::Move && pressed.is_some());
+
+    let targets = match (event.kind, pressed) {
+        (PointerEventType::Press, _) => {
+            let targets = hit();
+
+            composer
+                .context
+                .pointers
+                .borrow_mut()
+                .pressed
+                .insert(event.id, downgrade(&targets));
+
+            targets
+        }
+        (PointerEventType::Enter | PointerEventType::Exit, _) => Vec::new(),
+        (_, Some(targets)) => targets,
+        (_, None) => hit(),
+    };
+
+    if event.pointer_type != PointerType::Touch {
+        let hovered = match event.kind {
+            PointerEventType::Exit | PointerEventType::Cancel => Vec::new(),
+            _ => hit(),
+        };
+
+        update_hover(composer, &event, &hovered);
+    }
+
+    deliver(&targets, &event);
+
+    event.is_consumed()
+}
@@ -0,0 +1,288 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    rc::{Rc, Weak},
+};
+
+use rumpose_layout::{Point2D, Rect2D};
+
+use crate::Scope;
+
+/// Direction to move the focus in, from the focused node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FocusDirection {
+    /// The next node in reading order, top to bottom and left to right.
+    Next,
+    Previous,
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+#[derive(Default)]
+struct FocusState {
+    focused: Option<Weak<FocusTarget>>,
+    targets: Vec<Weak<FocusTarget>>,
+}
+
+thread_local! {
+    /// Like states, focus is not tied to a composer, so subcompositions share
+    /// it with their parents.
+    static FOCUS: RefCell<FocusState> = RefCell::default();
+}
+
+/// Receiver of the focus in a [`focus_target`](crate::components::focus_target)
+/// node. It outlives recompositions, so the node keeps the focus across them.
+pub struct FocusTarget {
+    can_focus: Cell<bool>,
+    focused: Cell<bool>,
+    on_focus_changed: RefCell<Rc<dyn Fn(bool)>>,
+    /// Area of the node when last drawn, ordering the traversal. Targets
+    /// never drawn cannot be reached by it.
+    area: Cell<Option<Rect2D>>,
+}
+
+impl FocusTarget {
+    pub(crate) fn new(can_focus: bool, on_focus_changed: Rc<dyn Fn(bool)>) -> Rc<Self> {
+        let target = Rc::new(Self {
+            can_focus: Cell::new(can_focus),
+            focused: Cell::new(false),
+            on_focus_changed: RefCell::new(on_focus_changed),
+            area: Cell::new(None),
+        });
+
+        FOCUS.with_borrow_mut(|focus| focus.targets.push(Rc::downgrade(&target)));
+
+        target
+    }
+
+    pub(crate) fn update(&self, can_focus: bool, on_focus_changed: Rc<dyn Fn(bool)>) {
+        self.can_focus.set(can_focus);
+        self.on_focus_changed.replace(on_focus_changed);
+
+        if !can_focus && self.is_focused() {
+            FocusManager.clear_focus();
+        }
+    }
+
+    pub(crate) fn set_area(&self, area: Rect2D) {
+        self.area.set(Some(area));
+    }
+
+    #[must_use]
+    pub fn is_focused(&self) -> bool {
+        self.focused.get()
+    }
+
+    /// Moves the focus to the target, returning `false` if it cannot be
+    /// focused.
+    pub fn request_focus(self: &Rc<Self>) -> bool {
+        if !self.can_focus.get() {
+            return false;
+        }
+
+        let previous = FOCUS.with_borrow_mut(|focus| {
+            focus
+                .focused
+                .replace(Rc::downgrade(self))
+                .and_then(|previous| previous.upgrade())
+        });
+
+        if let Some(previous) = previous.filter(|previous| !Rc::ptr_eq(previous, self)) {
+            previous.set_focused(false);
+        }
+
+        self.set_focused(true);
+
+        true
+    }
+
+    /// Calls the handler outside of any borrow, so it can move the focus.
+    fn set_focused(&self, value: bool) {
+        if self.focused.replace(value) != value {
+            let on_focus_changed = self.on_focus_changed.borrow().clone();
+
+            on_focus_changed(value);
+        }
+    }
+
+    fn center(&self) -> Option<Point2D> {
+        self.area.get().map(|area| area.origin + area.size.center())
+    }
+}
+
+impl fmt::Debug for FocusTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FocusTarget")
+            .field("can_focus", &self.can_focus.get())
+            .field("focused", &self.focused.get())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Moves the focus between the focus targets of every composer on the
+/// thread, which are ordered by the position of their nodes.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FocusManager;
+
+impl FocusManager {
+    /// Returns the focused target, if any.
+    #[must_use]
+    pub fn focused(&self) -> Option<Rc<FocusTarget>> {
+        FOCUS.with_borrow(|focus| focus.focused.as_ref().and_then(Weak::upgrade))
+    }
+
+    #[must_use]
+    pub fn has_focus(&self) -> bool {
+        self.focused().is_some()
+    }
+
+    pub fn clear_focus(&self) {
+        let previous = FOCUS.with_borrow_mut(|focus| focus.focused.take());
+
+        if let Some(previous) = previous.and_then(|previous| previous.upgrade()) {
+            previous.set_focused(false);
+        }
+    }
+
+    /// Focuses the closest target in `direction`, or the first one in that
+    /// direction if nothing is focused, and returns `false` if there is none.
+    pub fn move_focus(&self, direction: FocusDirection) -> bool {
+        let mut targets = FOCUS.with_borrow_mut(|focus| {
+            focus.targets.retain(|target| target.strong_count() > 0);
+            focus
+                .targets
+                .iter()
+                .filter_map(Weak::upgrade)
+                .filter(|target| target.can_focus.get())
+                .filter_map(|target| Some((target.area.get()?, target)))
+                .collect::<Vec<_>>()
+        });
+
+        targets.sort_by(|(a, _), (b, _)| {
+            a.origin
+                .y
+                .total_cmp(&b.origin.y)
+                .then(a.origin.x.total_cmp(&b.origin.x))
+        });
+
+        let targets = targets
+            .into_iter()
+            .map(|(_, target)| target)
+            .collect::<Vec<_>>();
+        let focused = self.focused();
+        let index = focused.as_ref().and_then(|focused| {
+            targets
+                .iter()
+                .position(|target| Rc::ptr_eq(target, focused))
+        });
+        let next = match (direction, index) {
+            (FocusDirection::Next, Some(index)) => targets.get((index + 1) % targets.len()),
+            (FocusDirection::Previous, Some(index)) => {
+                targets.get((index + targets.len() - 1) % targets.len())
+            }
+            (FocusDirection::Next | FocusDirection::Right | FocusDirection::Down, None) => {
+                targets.first()
+            }
+            (FocusDirection::Previous | FocusDirection::Left | FocusDirection::Up, None) => {
+                targets.last()
+            }
+            (_, Some(index)) => Self::closest(&targets, index, direction),
+        };
+
+        next.cloned().is_some_and(|next| next.request_focus())
+    }
+
+    /// Returns the target nearest to the one at `index` among those whose
+    /// center is in `direction`, favoring the ones aligned with it.
+    fn closest(
+        targets: &[Rc<FocusTarget>],
+        index: usize,
+        direction: FocusDirection,
+    ) -> Option<&Rc<FocusTarget>> {
+        let from = targets[index].center()?;
+
+        targets
+            .iter()
+            .filter_map(|target| Some((target, target.center()? - from)))
+            .filter_map(|(target, offset)| {
+                let (along, across) = match direction {
+                    FocusDirection::Left => (-offset.x, offset.y),
+                    FocusDirection::Right => (offset.x, offset.y),
+                    FocusDirection::Up => (-offset.y, offset.x),
+                    FocusDirection::Down => (offset.y, offset.x),
+                    FocusDirection::Next | FocusDirection::Previous => return None,
+                };
+
+                (along > 0.).then(|| (target, along + 2. * across.abs()))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(target, _)| target)
+    }
+}
+
+/// Moves the focus to the [`focus_target`](crate::components::focus_target)
+/// nodes it is passed to, e.g. to focus a text field when a screen opens.
+#[derive(Clone)]
+pub struct FocusRequester {
+    targets: Rc<RefCell<Vec<Weak<FocusTarget>>>>,
+}
+
+impl FocusRequester {
+    #[track_caller]
+    #[must_use]
+    pub fn new(scope: Scope) -> Self {
+        Self {
+            targets: scope.use_state(Rc::default).with(Clone::clone),
+        }
+    }
+
+    /// Focuses the first target that can be focused, returning `false` if
+    /// there is none, e.g. before the targets are composed.
+    pub fn request_focus(&self) -> bool {
+        self.targets().iter().any(|target| target.request_focus())
+    }
+
+    /// Clears the focus if one of the targets has it.
+    pub fn free_focus(&self) {
+        if self.targets().iter().any(|target| target.is_focused()) {
+            FocusManager.clear_focus();
+        }
+    }
+
+    pub(crate) fn attach(&self, target: &Rc<FocusTarget>) {
+        let mut targets = self.targets.borrow_mut();
+
+        targets.retain(|target| target.strong_count() > 0);
+
+        if !targets
+            .iter()
+            .any(|attached| attached.as_ptr() == Rc::as_ptr(target))
+        {
+            targets.push(Rc::downgrade(target));
+        }
+    }
+
+    fn targets(&self) -> Vec<Rc<FocusTarget>> {
+        self.targets
+            .borrow()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .collect()
+    }
+}
+
+impl PartialEq for FocusRequester {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.targets, &other.targets)
+    }
+}
+
+impl fmt::Debug for FocusRequester {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FocusRequester")
+            .field(&Rc::as_ptr(&self.targets))
+            .finish()
+    }
+}
@@ -0,0 +1,131 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// Key producing a character, given as typed with the active layout and
+    /// modifiers, e.g. `'A'` with shift held.
+    Character(char),
+    Enter,
+    Tab,
+    Backspace,
+    Delete,
+    Escape,
+    ArrowLeft,
+    ArrowRight,
+    ArrowUp,
+    ArrowDown,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Shift,
+    Control,
+    Alt,
+    Meta,
+    Unidentified,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyEventType {
+    #[default]
+    KeyDown,
+    KeyUp,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyModifiers {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+impl KeyModifiers {
+    pub const ALT: Self = Self {
+        alt: true,
+        ..Self::NONE
+    };
+    pub const CONTROL: Self = Self {
+        control: true,
+        ..Self::NONE
+    };
+    pub const META: Self = Self {
+        meta: true,
+        ..Self::NONE
+    };
+    pub const NONE: Self = Self {
+        shift: false,
+        control: false,
+        alt: false,
+        meta: false,
+    };
+    pub const SHIFT: Self = Self {
+        shift: true,
+        ..Self::NONE
+    };
+
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            shift: self.shift || other.shift,
+            control: self.control || other.control,
+            alt: self.alt || other.alt,
+            meta: self.meta || other.meta,
+        }
+    }
+
+    /// Returns `true` if the modifier of platform shortcuts, control or
+    /// command, is held.
+    #[must_use]
+    pub const fn is_shortcut(self) -> bool {
+        self.control || self.meta
+    }
+}
+
+/// Keyboard event passed to
+/// [`ComposerExt::dispatch_key_event`](crate::ComposerExt::dispatch_key_event).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    pub key: Key,
+    pub kind: KeyEventType,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyEvent {
+    #[must_use]
+    pub const fn new(key: Key, kind: KeyEventType) -> Self {
+        Self {
+            key,
+            kind,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[must_use]
+    pub const fn down(key: Key) -> Self {
+        Self::new(key, KeyEventType::KeyDown)
+    }
+
+    #[must_use]
+    pub const fn up(key: Key) -> Self {
+        Self::new(key, KeyEventType::KeyUp)
+    }
+
+    #[must_use]
+    pub const fn with_modifiers(mut self, modifiers: KeyModifiers) -> Self {
+        self.modifiers = modifiers;
+
+        self
+    }
+
+    /// Returns the character to insert for this event, if it types one.
+    #[must_use]
+    pub const fn typed_char(&self) -> Option<char> {
+        match self.key {
+            Key::Character(char)
+                if matches!(self.kind, KeyEventType::KeyDown) && !self.modifiers.is_shortcut() =>
+            {
+                Some(char)
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+mod dispatch;
+mod focus;
+mod key;
+mod pointer;
+
+pub(crate) use self::dispatch::{PointerTracker, dispatch_pointer, hit_test};
+pub use self::{
+    focus::{FocusDirection, FocusManager, FocusRequester, FocusTarget},
+    key::{Key, KeyEvent, KeyEventType, KeyModifiers},
+    pointer::{
+        PointerButton, PointerEvent, PointerEventPass, PointerEventType, PointerId, PointerType,
+    },
+};
@@ -0,0 +1,207 @@
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use rumpose_layout::{Density, Point2D, Size2D};
+
+use super::KeyModifiers;
+
+/// Identifies a pointer across its events, e.g. a touch from press to
+/// release.
+pub type PointerId = u64;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointerType {
+    #[default]
+    Mouse,
+    Touch,
+    Pen,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointerButton {
+    #[default]
+    Primary,
+    Secondary,
+    Middle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointerEventType {
+    Press,
+    Move,
+    Release,
+    /// The host took the pointer away, e.g. for a system gesture.
+    Cancel,
+    /// A hovering pointer moved over the node. Sent by the composer.
+    Enter,
+    /// A hovering pointer left the node. Sent by the composer.
+    Exit,
+    Scroll,
+}
+
+/// Order in which a pointer event reaches the nodes it hit: from the root to
+/// the deepest node, back up, and down again. Parents can act before their
+/// children in [`Initial`](PointerEventPass::Initial), or only on what their
+/// children left unconsumed in [`Main`](PointerEventPass::Main).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointerEventPass {
+    Initial,
+    Main,
+    Final,
+}
+
+/// Pointer event passed to
+/// [`ComposerExt::dispatch_pointer_event`](crate::ComposerExt::dispatch_pointer_event)
+/// in the coordinates of the tree. Handlers receive it with `position`
+/// relative to their node, with its transforms undone, and `size` set to the
+/// size of the node.
+#[derive(Debug, Clone)]
+pub struct PointerEvent {
+    pub id: PointerId,
+    pub kind: PointerEventType,
+    pub pointer_type: PointerType,
+    pub button: PointerButton,
+    /// Whether the pointer is down, set by the composer.
+    pub pressed: bool,
+    pub position: Point2D,
+    pub size: Size2D,
+    /// Distance scrolled by a [`Scroll`](PointerEventType::Scroll) event.
+    pub scroll_delta: Point2D,
+    pub modifiers: KeyModifiers,
+    /// Time of the event since an arbitrary point chosen by the host.
+    pub time: Duration,
+    /// Density of the tree, set by the composer.
+    pub density: Density,
+    /// Number of the dispatch delivering the event, set by the composer. It
+    /// differs for every call of
+    /// [`ComposerExt::dispatch_pointer_event`](crate::ComposerExt::dispatch_pointer_event),
+    /// so handlers can tell events apart even when their pointers and times
+    /// are equal.
+    pub dispatch: u64,
+    consumed: Rc<Cell<bool>>,
+}
+
+impl PointerEvent {
+    #[must_use]
+    pub fn new(kind: PointerEventType, position: Point2D) -> Self {
+        Self {
+            id: 0,
+            kind,
+            pointer_type: PointerType::default(),
+            button: PointerButton::default(),
+            pressed: false,
+            position,
+            size: Size2D::default(),
+            scroll_delta: Point2D::default(),
+            modifiers: KeyModifiers::NONE,
+            time: Duration::ZERO,
+            density: Density::default(),
+            dispatch: 0,
+            consumed: Rc::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn press(position: Point2D) -> Self {
+        Self::new(PointerEventType::Press, position)
+    }
+
+    #[must_use]
+    pub fn moved(position: Point2D) -> Self {
+        Self::new(PointerEventType::Move, position)
+    }
+
+    #[must_use]
+    pub fn release(position: Point2D) -> Self {
+        Self::new(PointerEventType::Release, position)
+    }
+
+    #[must_use]
+    pub fn scroll(position: Point2D, delta: Point2D) -> Self {
+        Self {
+            scroll_delta: delta,
+            ..Self::new(PointerEventType::Scroll, position)
+        }
+    }
+
+    #[must_use]
+    pub const fn with_id(mut self, id: PointerId) -> Self {
+        self.id = id;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn with_type(mut self, pointer_type: PointerType) -> Self {
+        self.pointer_type = pointer_type;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn with_button(mut self, button: PointerButton) -> Self {
+        self.button = button;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn with_modifiers(mut self, modifiers: KeyModifiers) -> Self {
+        self.modifiers = modifiers;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn with_time(mut self, time: Duration) -> Self {
+        self.time = time;
+
+        self
+    }
+
+    /// Sets the size of the node receiving the event, which the composer sets
+    /// when dispatching. Synthetic events passed straight to handlers need it
+    /// for [`is_inside`](Self::is_inside).
+    #[must_use]
+    pub const fn with_size(mut self, size: Size2D) -> Self {
+        self.size = size;
+
+        self
+    }
+
+    /// Marks the event as handled. Every node receiving it afterwards sees it
+    /// as consumed.
+    pub fn consume(&self) {
+        self.consumed.set(true);
+    }
+
+    #[must_use]
+    pub fn is_consumed(&self) -> bool {
+        self.consumed.get()
+    }
+
+    /// Returns `true` if `position` is inside the node.
+    #[must_use]
+    pub fn is_inside(&self) -> bool {
+        (0. ..=self.size.width).contains(&self.position.x)
+            && (0. ..=self.size.height).contains(&self.position.y)
+    }
+
+    /// Returns the event as seen by a node, sharing the consumed flag.
+    pub(crate) fn localized(&self, position: Point2D, size: Size2D) -> Self {
+        Self {
+            position,
+            size,
+            ..self.clone()
+        }
+    }
+
+    /// Returns an event of another kind for the same pointer, with its own
+    /// consumed flag.
+    pub(crate) fn with_kind(&self, kind: PointerEventType) -> Self {
+        Self {
+            kind,
+            consumed: Rc::default(),
+            ..self.clone()
+        }
+    }
+}
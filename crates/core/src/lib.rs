@@ -1,26 +1,47 @@
-use std::cell::RefCell;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
 
-use node::NodeExt;
+use input::{
+    FocusDirection, FocusManager, Key, KeyEvent, KeyEventType, PointerEvent, PointerTracker,
+};
+use node::{KeyInputPhase, Node, NodeExt, NodePhase};
 use phase::{LayoutContext, RenderContext};
 use rumpose_engine::{
-    EncodedImageFormat, FontCollection, FontManager, Image, Surface, create_surface,
+    ClipOp, EngineColor, Image, Matrix, Path, Picture, PictureRecorder, Rect, Surface, SvgCanvas,
+    create_surface, pdf,
 };
-use rumpose_layout::Constraints;
+use rumpose_layout::{Constraints, Density, Rect2D};
 
 mod components;
+mod encode;
+mod font;
+mod frame;
+mod input;
 mod node;
 mod phase;
 
 pub mod prelude {
-    pub use rumpose_engine::*;
+    pub use rumpose_engine::{
+        graphics::{Color, ColorSpace},
+        *,
+    };
     pub use rumpose_layout::*;
 
     pub use crate::{
-        Composer, ComposerExt, Recomposer, RumposeContext, RuntimeNode, Scope, components::*,
-        node::*, phase::*,
+        Composer, ComposerExt, EncodeError, FontError, FontRegistry, ImageFormat, Recomposer,
+        RumposeContext, RuntimeNode, Scope, State, components::*, frame::*, input::*, node::*,
+        phase::*,
     };
 }
 
+pub use self::{
+    encode::{EncodeError, ImageFormat},
+    font::{FontError, FontRegistry},
+};
+
 pub type RuntimeNode = rumpose_runtime::Node<node::Node>;
 pub type Composer = rumpose_runtime::Composer<node::Node>;
 pub type Recomposer = rumpose_runtime::Recomposer<(), node::Node>;
@@ -29,41 +50,439 @@ pub type State<T> = rumpose_runtime::State<T, node::Node>;
 
 pub struct RumposeContext {
     root: Constraints,
-    surface: RefCell<Surface>,
-    font_manager: FontCollection,
+    density: Rc<Cell<Density>>,
+    surface: Rc<RefCell<Surface>>,
+    recorders: Rc<RefCell<Vec<PictureRecorder>>>,
+    /// Set when the tree was drawn somewhere else than the surface, so the
+    /// surface misses the drawings cached meanwhile.
+    stale: Cell<bool>,
+    /// Areas repainted by the current draw pass. Clean nodes outside them are
+    /// skipped when drawing to the surface.
+    damage: Rc<RefCell<Vec<Rect2D>>>,
+    fonts: FontRegistry,
+    pointers: RefCell<PointerTracker>,
 }
 
 impl RumposeContext {
-    pub fn new(width: u16, height: u16) -> Self {
+    /// Allocates a surface of at least one pixel, as skia cannot create empty
+    /// ones, e.g. for a minimised window.
+    fn allocate_surface(width: u32, height: u32) -> Surface {
+        let width = i32::try_from(width.max(1)).expect("surface width is too large");
+        let height = i32::try_from(height.max(1)).expect("surface height is too large");
+
+        create_surface((width, height)).expect("failed to create surface")
+    }
+
+    pub fn new(width: u32, height: u32) -> Self {
         Self {
             root: Constraints::new(0., width as f32, 0., height as f32),
-            surface: RefCell::new(create_surface((i32::from(width), i32::from(height))).unwrap()),
-            font_manager: {
-                let mut collection = FontCollection::new();
-
-                collection.set_default_font_manager(FontManager::new(), "Arial");
+            density: Rc::default(),
+            surface: Rc::new(RefCell::new(Self::allocate_surface(width, height))),
+            recorders: Rc::default(),
+            stale: Cell::new(false),
+            damage: Rc::default(),
+            fonts: FontRegistry::new(),
+            pointers: RefCell::default(),
+        }
+    }
 
-                collection
-            },
+    /// Creates a context for a subcomposition, drawing to the same surface and
+    /// recording.
+    pub(crate) fn subcontext(&self, root: Constraints) -> Self {
+        Self {
+            root,
+            density: self.density.clone(),
+            surface: self.surface.clone(),
+            recorders: self.recorders.clone(),
+            stale: Cell::new(false),
+            damage: self.damage.clone(),
+            fonts: self.fonts.clone(),
+            pointers: RefCell::default(),
         }
     }
 
+    fn bounds(&self) -> Rect {
+        let surface = self.surface.borrow();
+
+        Rect::from_iwh(surface.width(), surface.height())
+    }
+
+    /// Records everything `draw` paints through
+    /// [`RenderContext::with_canvas`] into a picture. Recordings nest, so the
+    /// innermost one receives the drawing.
+    pub(crate) fn record(&self, draw: impl FnOnce()) -> Picture {
+        let mut recorder = PictureRecorder::new();
+
+        recorder.begin_recording(self.bounds(), None);
+
+        self.recorders.borrow_mut().push(recorder);
+
+        draw();
+
+        self.recorders
+            .borrow_mut()
+            .pop()
+            .and_then(|mut recorder| recorder.finish_recording_as_picture(None))
+            .expect("recording was not started")
+    }
+
+    /// Returns `true` while drawing goes into a recording rather than the
+    /// surface.
+    pub(crate) fn is_recording(&self) -> bool {
+        !self.recorders.borrow().is_empty()
+    }
+
+    /// Returns `true` if `rect` overlaps the areas repainted by the current
+    /// draw pass.
+    pub(crate) fn is_damaged(&self, rect: Rect2D) -> bool {
+        self.damage
+            .borrow()
+            .iter()
+            .any(|damage| damage.intersects(&rect))
+    }
+
+    /// Sets the density used to convert [`Dp`](rumpose_layout::Dp) and
+    /// [`Sp`](rumpose_layout::Sp) to pixels, e.g. `2.` to render at twice the
+    /// size for HiDPI outputs.
+    #[must_use]
+    pub fn with_density(self, density: Density) -> Self {
+        self.density.set(density);
+
+        self
+    }
+
+    #[must_use]
+    pub fn density(&self) -> Density {
+        self.density.get()
+    }
+
+    #[must_use]
+    pub const fn fonts(&self) -> &FontRegistry {
+        &self.fonts
+    }
+
+    pub const fn fonts_mut(&mut self) -> &mut FontRegistry {
+        &mut self.fonts
+    }
+
+    /// Reallocates the surface with the given size and makes it the root
+    /// constraints. Subcompositions share the surface, so they draw to the new
+    /// one as well.
+    fn resize(&mut self, width: u32, height: u32) {
+        self.root = Constraints::new(0., width as f32, 0., height as f32);
+        self.surface.replace(Self::allocate_surface(width, height));
+    }
+
+    #[must_use]
+    pub fn size(&self) -> (u32, u32) {
+        let surface = self.surface.borrow();
+
+        (surface.width() as u32, surface.height() as u32)
+    }
+
     pub fn snapshot(&self) -> Image {
         self.surface.borrow_mut().image_snapshot()
     }
 
+    /// Encodes the surface as a PNG image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if encoding fails; use [`encode_as`](Self::encode_as) to handle
+    /// the error.
     pub fn encode(&self) -> Vec<u8> {
-        self.snapshot()
-            .encode(None, EncodedImageFormat::PNG, 100)
-            .map(|data| data.to_vec())
-            .unwrap()
+        self.encode_as(ImageFormat::Png, 100)
+            .expect("failed to encode surface")
+    }
+
+    /// Encodes recorded pages (see [`ComposerExt::record`]) as a PDF document,
+    /// one page per picture.
+    #[must_use]
+    pub fn encode_pdf(pages: &[Picture]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut document = pdf::new_document(&mut bytes, None);
+
+        for page in pages {
+            let mut current = document.begin_page(page.cull_rect().size(), None);
+
+            current.canvas().draw_picture(page, None, None);
+
+            document = current.end_page();
+        }
+
+        document.close();
+
+        bytes
+    }
+
+    /// Encodes a recorded page (see [`ComposerExt::record`]) as an SVG
+    /// document.
+    #[must_use]
+    pub fn encode_svg(page: &Picture) -> Vec<u8> {
+        let canvas = SvgCanvas::new(page.cull_rect(), None);
+
+        canvas.draw_picture(page, None, None);
+
+        canvas.end().as_bytes().to_vec()
     }
 }
 
 pub trait ComposerExt {
     fn compute_layout(&self);
-    fn draw_all(&mut self);
+    /// Repaints the parts of the surface covered by nodes that are
+    /// render-dirty or moved, and returns those damaged rectangles so the host
+    /// can present only them. Only dirty nodes run their draw closures; clean
+    /// ones replay the picture cached on their last draw, clipped to the
+    /// damage.
+    fn draw_all(&mut self) -> Vec<Rect2D>;
+    /// Draws the whole tree into a picture that can be encoded with
+    /// [`RumposeContext::encode_pdf`] or [`RumposeContext::encode_svg`]. The
+    /// surface is left as it was; the next [`draw_all`](Self::draw_all)
+    /// repaints all of it.
+    fn record(&mut self) -> Picture;
+    /// Resizes the surface and the root constraints, and marks the whole tree
+    /// dirty so the next layout and draw pass fill the new surface. An empty
+    /// size, e.g. of a minimised window, keeps a one pixel surface.
+    fn resize(&mut self, width: u32, height: u32);
+    /// Changes the density, shared with subcompositions, and marks the whole
+    /// tree dirty so the next layout and draw pass use it, e.g. when a window
+    /// moves to a screen with another scale factor.
+    fn set_density(&mut self, density: Density);
     fn mark_dirty(&self, id: usize);
+    fn mark_render_dirty(&self, id: usize);
+    /// Returns `true` if the node or any of its descendants has to be drawn
+    /// again, as cached on the nodes when drawing starts.
+    fn is_render_dirty(&self, id: usize) -> bool;
+    /// Returns the unmerged areas of the node and its descendants that change
+    /// on the next draw.
+    fn damage(&self, id: usize) -> Vec<Rect2D>;
+    /// Passes a key event to the
+    /// [`preview_key_input`](components::preview_key_input) nodes on the
+    /// way from the root to the focused
+    /// [`focus_target`](components::focus_target), then to the
+    /// [`key_input`](components::key_input) nodes on the way back, until one
+    /// consumes it. Unconsumed tab and arrow keys move the focus, see
+    /// [`FocusManager::move_focus`]. Returns `true` if the event was consumed.
+    fn dispatch_key_event(&self, event: &KeyEvent) -> bool;
+    /// Hit tests `event` against the laid out tree and passes it to the
+    /// [`pointer_input`](components::pointer_input) nodes under it, through
+    /// every [`PointerEventPass`](input::PointerEventPass). Pressed pointers
+    /// keep reaching the nodes they were pressed on until released, and
+    /// hovering pointers send enter and exit events. Returns `true` if a node
+    /// consumed the event.
+    fn dispatch_pointer_event(&self, event: PointerEvent) -> bool;
+    /// Runs the callbacks passed to [`request_frame`](frame::request_frame)
+    /// with `time`, on the same clock as [`PointerEvent::time`], and returns
+    /// `true` if there were any. Hosts call it once per frame before
+    /// recomposing while [`has_frame_requests`](frame::has_frame_requests)
+    /// returns `true`.
+    fn advance_frame(&self, time: Duration) -> bool;
+}
+
+/// Merges overlapping rectangles and rounds them out to whole pixels inside
+/// `bounds`.
+fn merge_damage(rects: Vec<Rect2D>, bounds: Rect2D) -> Vec<Rect2D> {
+    let mut merged: Vec<Rect2D> = Vec::new();
+
+    for rect in rects {
+        let x = rect.min_x().floor().max(bounds.min_x());
+        let y = rect.min_y().floor().max(bounds.min_y());
+        let mut rect = Rect2D::from_xywh(
+            x,
+            y,
+            rect.max_x().ceil().min(bounds.max_x()) - x,
+            rect.max_y().ceil().min(bounds.max_y()) - y,
+        );
+
+        if rect.width() <= 0. || rect.height() <= 0. {
+            continue;
+        }
+
+        while let Some(index) = merged.iter().position(|other| other.intersects(&rect)) {
+            rect = rect.union(&merged.swap_remove(index));
+        }
+
+        merged.push(rect);
+    }
+
+    merged
+}
+
+/// Collects the key input nodes from `id` down to the focused
+/// [`focus_target`](components::focus_target), and the ones the focused node
+/// wraps directly, such as the modifiers following it. Returns `false`,
+/// leaving `path` as it was, if the focused node is not in the subtree.
+pub(crate) fn focus_path(composer: &Composer, id: usize, path: &mut Vec<KeyInputPhase>) -> bool {
+    let node = &composer.nodes[id];
+    let len = path.len();
+
+    match node.data.as_ref().map(|data| &data.phase) {
+        Some(NodePhase::KeyInput(phase)) => path.push(phase.clone()),
+        Some(NodePhase::Focus(phase)) if phase.target.is_focused() => {
+            let mut node = node;
+
+            while let [id] = node.children[..] {
+                node = &composer.nodes[id];
+
+                match node.data.as_ref().map(|data| &data.phase) {
+                    Some(NodePhase::KeyInput(phase)) => path.push(phase.clone()),
+                    Some(NodePhase::Focus(_)) => break,
+                    _ => {}
+                }
+            }
+
+            return true;
+        }
+        Some(NodePhase::MeasurementCompose(phase)) => return phase.context.focus_path(path),
+        _ => {}
+    }
+
+    if node
+        .children
+        .iter()
+        .any(|&id| focus_path(composer, id, path))
+    {
+        return true;
+    }
+
+    path.truncate(len);
+
+    false
+}
+
+/// Returns the direction a traversal key moves the focus in.
+const fn focus_direction(event: &KeyEvent) -> Option<FocusDirection> {
+    match event.key {
+        Key::Tab if event.modifiers.shift => Some(FocusDirection::Previous),
+        Key::Tab => Some(FocusDirection::Next),
+        Key::ArrowLeft => Some(FocusDirection::Left),
+        Key::ArrowRight => Some(FocusDirection::Right),
+        Key::ArrowUp => Some(FocusDirection::Up),
+        Key::ArrowDown => Some(FocusDirection::Down),
+        _ => None,
+    }
+}
+
+/// Marks the ancestors of layout-dirty nodes dirty as well, so the layout
+/// pass reaches them from the root. Returns `true` if the node was marked.
+fn propagate_layout_dirty(composer: &Composer, id: usize) -> bool {
+    let node = &composer.nodes[id];
+    let mut dirty = false;
+
+    for &id in &node.children {
+        dirty |= propagate_layout_dirty(composer, id);
+    }
+
+    match node.data.as_ref() {
+        Some(data) if dirty => {
+            data.layout_dirty.set(true);
+
+            true
+        }
+        Some(data) => data.layout_dirty.get(),
+        None => dirty,
+    }
+}
+
+/// Caches on every node whether it or one of its descendants has to be drawn
+/// again, and the area of the surface the subtree covers, with `matrix`
+/// mapping the node to the surface. Nodes that covered a `disposed` area are
+/// drawn again, as their pictures still show the disposed nodes. Returns
+/// whether the subtree is dirty and the area it covers.
+pub(crate) fn propagate_render_dirty(
+    composer: &Composer,
+    id: usize,
+    matrix: &Matrix,
+    disposed: &[Rect2D],
+) -> (bool, Option<Rect2D>) {
+    let node = &composer.nodes[id];
+
+    let Some(data) = node.data.as_ref() else {
+        return node
+            .children
+            .iter()
+            .fold((false, None), |(dirty, bounds), &id| {
+                let (child_dirty, child_bounds) =
+                    propagate_render_dirty(composer, id, matrix, disposed);
+
+                (dirty || child_dirty, union_bounds(bounds, child_bounds))
+            });
+    };
+
+    let area = *data.area.borrow();
+    let matrix = match &data.phase {
+        NodePhase::Layer(phase) => Matrix::concat(matrix, &phase.props.matrix(area)),
+        _ => *matrix,
+    };
+
+    let previous = data.bounds.get();
+    let mut dirty = disposed.iter().any(|rect| rect.intersects(&previous));
+    let mut bounds =
+        Some(map_rect(&matrix, area)).filter(|rect| rect.width() > 0. && rect.height() > 0.);
+
+    for &id in &node.children {
+        let (child_dirty, child_bounds) = propagate_render_dirty(composer, id, &matrix, disposed);
+
+        dirty |= child_dirty;
+        bounds = union_bounds(bounds, child_bounds);
+    }
+
+    if let NodePhase::MeasurementCompose(phase) = &data.phase {
+        let (child_dirty, child_bounds) = phase.context.propagate_render_dirty(&matrix, disposed);
+
+        dirty |= child_dirty;
+        bounds = union_bounds(bounds, child_bounds);
+    }
+
+    dirty |= data.needs_render();
+
+    data.subtree_render_dirty.set(dirty);
+    data.bounds.set(bounds.unwrap_or_default());
+
+    (dirty, bounds)
+}
+
+pub(crate) fn union_bounds(a: Option<Rect2D>, b: Option<Rect2D>) -> Option<Rect2D> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.union(&b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn map_rect(matrix: &Matrix, rect: Rect2D) -> Rect2D {
+    Rect2D::from(matrix.map_rect(Rect::from(rect)).0)
+}
+
+/// Returns the z-index of a node: the one of its layer, or of the layer
+/// wrapped by a chain of single child nodes, such as modifiers.
+fn z_index(composer: &Composer, id: usize) -> f32 {
+    let node = &composer.nodes[id];
+
+    match node.data.as_ref().map(|data| &data.phase) {
+        Some(NodePhase::Layer(phase)) => phase.props.z_index,
+        Some(NodePhase::MeasurementCompose(_)) => 0.,
+        _ if node.children.len() == 1 => z_index(composer, node.children[0]),
+        _ => 0.,
+    }
+}
+
+/// Returns the children of a node in the order they are drawn, by increasing
+/// z-index.
+pub(crate) fn draw_order(composer: &Composer, node: &RuntimeNode) -> Vec<usize> {
+    let mut children = node.children.clone();
+
+    if children.len() > 1 {
+        children.sort_by(|&a, &b| z_index(composer, a).total_cmp(&z_index(composer, b)));
+    }
+
+    children
+}
+
+/// Draws the tree of `composer` into the current canvas.
+pub(crate) fn draw_root(composer: &Composer) {
+    let node = &composer.nodes[composer.root_node_key()];
+
+    node.draw(RenderContext::new(node, composer));
 }
 
 impl ComposerExt for Composer {
@@ -71,27 +490,187 @@ impl ComposerExt for Composer {
     fn mark_dirty(&self, id: usize) {
         let node = &self.nodes[id];
 
-        node.data.as_ref().inspect(|data| data.mark_dirty());
+        if let Some(data) = node.data.as_ref() {
+            data.mark_dirty();
+
+            if let NodePhase::MeasurementCompose(phase) = &data.phase {
+                phase.context.mark_dirty();
+            }
+        }
 
         for &id in &node.children {
             self.mark_dirty(id);
         }
     }
 
+    #[track_caller]
+    fn resize(&mut self, width: u32, height: u32) {
+        self.context.resize(width, height);
+        self.mark_dirty(self.root_node_key());
+    }
+
+    #[track_caller]
+    fn set_density(&mut self, density: Density) {
+        self.context.density.set(density);
+        self.mark_dirty(self.root_node_key());
+    }
+
+    #[track_caller]
+    fn mark_render_dirty(&self, id: usize) {
+        let node = &self.nodes[id];
+
+        if let Some(data) = node.data.as_ref() {
+            data.mark_render_dirty();
+
+            if let NodePhase::MeasurementCompose(phase) = &data.phase {
+                phase.context.mark_render_dirty();
+            }
+        }
+
+        for &id in &node.children {
+            self.mark_render_dirty(id);
+        }
+    }
+
+    #[track_caller]
+    fn is_render_dirty(&self, id: usize) -> bool {
+        let node = &self.nodes[id];
+
+        match node.data.as_ref() {
+            Some(data) => data.subtree_render_dirty.get(),
+            None => node.children.iter().any(|&id| self.is_render_dirty(id)),
+        }
+    }
+
+    #[track_caller]
+    fn damage(&self, id: usize) -> Vec<Rect2D> {
+        let node = &self.nodes[id];
+        let mut damage = node.data.as_ref().map(Node::damage).unwrap_or_default();
+
+        for &id in &node.children {
+            damage.extend(self.damage(id));
+        }
+
+        if let Some(data) = node.data.as_ref() {
+            if let NodePhase::Layer(phase) = &data.phase {
+                let area = *data.area.borrow();
+                let matrix = phase.props.matrix(area);
+
+                damage = damage
+                    .into_iter()
+                    .map(|rect| map_rect(&matrix, rect))
+                    .collect();
+
+                // The content moved as a whole, so the areas it covered with
+                // the previous transform and covers now change.
+                if let Some(drawn) = phase.drawn.get().filter(|drawn| *drawn != matrix) {
+                    damage.extend([map_rect(&drawn, area), map_rect(&matrix, area)]);
+                }
+            }
+        }
+
+        damage
+    }
+
+    #[track_caller]
+    fn dispatch_key_event(&self, event: &KeyEvent) -> bool {
+        let mut path = Vec::new();
+
+        if focus_path(self, self.root_node_key(), &mut path) {
+            let (preview, regular): (Vec<_>, Vec<_>) =
+                path.into_iter().partition(|phase| phase.preview);
+
+            if preview
+                .iter()
+                .chain(regular.iter().rev())
+                .any(|phase| (phase.handler)(event))
+            {
+                return true;
+            }
+        }
+
+        event.kind == KeyEventType::KeyDown
+            && focus_direction(event).is_some_and(|direction| FocusManager.move_focus(direction))
+    }
+
+    #[track_caller]
+    fn dispatch_pointer_event(&self, event: PointerEvent) -> bool {
+        input::dispatch_pointer(self, event)
+    }
+
+    #[track_caller]
+    fn advance_frame(&self, time: Duration) -> bool {
+        frame::run_frame(time)
+    }
+
     #[track_caller]
     fn compute_layout(&self) {
         let root_node = self.root_node_key();
         let node = &self.nodes[root_node];
 
+        propagate_layout_dirty(self, root_node);
+
         node.measure(LayoutContext::new(self), self.context.root);
     }
 
     #[track_caller]
-    fn draw_all(&mut self) {
+    fn draw_all(&mut self) -> Vec<Rect2D> {
         let root_node = self.root_node_key();
 
-        let node = &self.nodes[root_node];
+        let stale = self.context.stale.replace(false);
+        let disposed = node::take_disposed();
+
+        if !propagate_render_dirty(self, root_node, &Matrix::default(), &disposed).0 && !stale {
+            return Vec::new();
+        }
+
+        let bounds = self.context.bounds();
+        let bounds = Rect2D::from_xywh(bounds.left, bounds.top, bounds.width(), bounds.height());
+        let damage = if stale {
+            vec![bounds]
+        } else {
+            let mut damage = self.damage(root_node);
+
+            damage.extend(disposed);
+
+            merge_damage(damage, bounds)
+        };
 
-        node.draw(RenderContext::new(node, self));
+        self.context.damage.replace(damage.clone());
+
+        {
+            let mut clip = Path::new();
+
+            for &rect in &damage {
+                clip.add_rect(Rect::from(rect), None);
+            }
+
+            let mut surface = self.context.surface.borrow_mut();
+            let canvas = surface.canvas();
+
+            canvas.save();
+            canvas.clip_path(&clip, ClipOp::Intersect, false);
+            canvas.clear(EngineColor::TRANSPARENT);
+        }
+
+        draw_root(self);
+
+        self.context.surface.borrow_mut().canvas().restore();
+
+        damage
+    }
+
+    #[track_caller]
+    fn record(&mut self) -> Picture {
+        // Drawing caches the pictures of the nodes, so they are not drawn to
+        // the surface by the next damage-based repaint.
+        self.context.stale.set(true);
+        propagate_render_dirty(
+            self,
+            self.root_node_key(),
+            &Matrix::default(),
+            &node::take_disposed(),
+        );
+        self.context.record(|| draw_root(self))
     }
 }
@@ -0,0 +1,92 @@
+use std::{fmt, rc::Rc};
+
+use crate::input::{FocusRequester, FocusTarget};
+
+#[derive(Clone)]
+pub struct FocusProps {
+    pub(crate) can_focus: bool,
+    pub(crate) requesters: Vec<FocusRequester>,
+    pub(crate) on_focus_changed: Rc<dyn Fn(bool)>,
+}
+
+impl Default for FocusProps {
+    fn default() -> Self {
+        Self {
+            can_focus: true,
+            requesters: Vec::new(),
+            on_focus_changed: Rc::new(|_| {}),
+        }
+    }
+}
+
+impl FocusProps {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disabled targets lose the focus and are skipped by the traversal.
+    #[must_use]
+    pub const fn can_focus(mut self, value: bool) -> Self {
+        self.can_focus = value;
+
+        self
+    }
+
+    #[must_use]
+    pub fn requester(mut self, requester: FocusRequester) -> Self {
+        self.requesters.push(requester);
+
+        self
+    }
+
+    /// Adds `callback`, called when the target gains or loses the focus
+    /// after the ones added before.
+    #[must_use]
+    pub fn on_focus_changed<F: Fn(bool) + 'static>(mut self, callback: F) -> Self {
+        let previous = self.on_focus_changed.clone();
+
+        self.on_focus_changed = Rc::new(move |focused| {
+            previous(focused);
+            callback(focused);
+        });
+
+        self
+    }
+}
+
+impl fmt::Debug for FocusProps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FocusProps")
+            .field("can_focus", &self.can_focus)
+            .field("requesters", &self.requesters)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FocusPhase {
+    pub(crate) target: Rc<FocusTarget>,
+}
+
+impl FocusPhase {
+    #[must_use]
+    pub fn new(props: &FocusProps) -> Self {
+        let target = FocusTarget::new(props.can_focus, props.on_focus_changed.clone());
+
+        for requester in &props.requesters {
+            requester.attach(&target);
+        }
+
+        Self { target }
+    }
+
+    pub fn update(&self, props: &FocusProps) {
+        self.target
+            .update(props.can_focus, props.on_focus_changed.clone());
+
+        for requester in &props.requesters {
+            requester.attach(&self.target);
+        }
+    }
+}
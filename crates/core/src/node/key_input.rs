@@ -0,0 +1,30 @@
+use std::{fmt, rc::Rc};
+
+use crate::input::KeyEvent;
+
+/// Handles a key event, returning `true` if it was consumed.
+pub type KeyHandler = Rc<dyn Fn(&KeyEvent) -> bool>;
+
+#[derive(Clone)]
+pub struct KeyInputPhase {
+    pub(crate) handler: KeyHandler,
+    /// Preview handlers run before the regular ones, from the root down.
+    pub(crate) preview: bool,
+}
+
+impl KeyInputPhase {
+    pub fn new<F: Fn(&KeyEvent) -> bool + 'static>(handler: F, preview: bool) -> Self {
+        Self {
+            handler: Rc::new(handler),
+            preview,
+        }
+    }
+}
+
+impl fmt::Debug for KeyInputPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyInputNode")
+            .field("preview", &self.preview)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,117 @@
+use std::{cell::Cell, fmt, rc::Rc};
+
+use rumpose_engine::{Matrix, Path, Point};
+use rumpose_layout::{Point2D, Rect2D};
+
+/// Builds the clip path of a layer from its area.
+pub type ClipPath = Rc<dyn Fn(Rect2D) -> Path>;
+
+/// Drawing order, transform and clip of a [`layer`](crate::components::layer)
+/// and its content, also applied when hit testing pointer events.
+#[derive(Clone)]
+pub struct LayerProps {
+    pub(crate) z_index: f32,
+    pub(crate) translation: Point2D,
+    pub(crate) scale_x: f32,
+    pub(crate) scale_y: f32,
+    pub(crate) rotation: f32,
+    pub(crate) clip: Option<ClipPath>,
+}
+
+impl Default for LayerProps {
+    fn default() -> Self {
+        Self {
+            z_index: 0.,
+            translation: Point2D::default(),
+            scale_x: 1.,
+            scale_y: 1.,
+            rotation: 0.,
+            clip: None,
+        }
+    }
+}
+
+impl LayerProps {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Siblings with a higher z-index are drawn above and receive pointer
+    /// events first. Siblings with the same z-index keep their order.
+    #[must_use]
+    pub const fn z_index(mut self, value: f32) -> Self {
+        self.z_index = value;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn translation(mut self, x: f32, y: f32) -> Self {
+        self.translation = Point2D::new(x, y);
+
+        self
+    }
+
+    /// Scales the content around its center.
+    #[must_use]
+    pub const fn scale(mut self, x: f32, y: f32) -> Self {
+        self.scale_x = x;
+        self.scale_y = y;
+
+        self
+    }
+
+    /// Rotates the content clockwise around its center, in degrees.
+    #[must_use]
+    pub const fn rotation(mut self, degrees: f32) -> Self {
+        self.rotation = degrees;
+
+        self
+    }
+
+    /// Clips the content to the path built from the layer area.
+    #[must_use]
+    pub fn clip<F: Fn(Rect2D) -> Path + 'static>(mut self, path: F) -> Self {
+        self.clip = Some(Rc::new(path));
+
+        self
+    }
+
+    /// Returns the transform of the layer placed at `area`.
+    #[must_use]
+    pub fn matrix(&self, area: Rect2D) -> Matrix {
+        let pivot = Point::from(area.origin + area.size.center());
+        let mut matrix = Matrix::translate((self.translation.x, self.translation.y));
+
+        matrix.pre_rotate(self.rotation, pivot);
+        matrix.pre_scale((self.scale_x, self.scale_y), pivot);
+
+        matrix
+    }
+}
+
+pub struct LayerPhase {
+    pub(crate) props: LayerProps,
+    /// Transform the layer was last drawn with, to damage the area it covered
+    /// when the transform changes.
+    pub(crate) drawn: Cell<Option<Matrix>>,
+}
+
+impl LayerPhase {
+    #[must_use]
+    pub fn new(props: LayerProps) -> Self {
+        Self {
+            props,
+            drawn: Cell::default(),
+        }
+    }
+}
+
+impl fmt::Debug for LayerPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LayerNode")
+            .field("z_index", &self.props.z_index)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,5 +1,9 @@
+mod focus;
+mod key_input;
+mod layer;
 mod measure;
 mod measure_compose;
+mod pointer_input;
 mod render;
 
 use std::{
@@ -7,12 +11,17 @@ use std::{
     fmt,
 };
 
+use rumpose_engine::{ClipOp, Picture};
 use rumpose_layout::{Constraints, Point2D, Rect2D, Size2D};
 use rumpose_runtime::ComposeNode;
 
 pub use self::{
+    focus::{FocusPhase, FocusProps},
+    key_input::{KeyHandler, KeyInputPhase},
+    layer::{ClipPath, LayerPhase, LayerProps},
     measure::{Measure, MeasurementPhase},
     measure_compose::{MeasureCompose, MeasurementComposePhase},
+    pointer_input::{PointerHandler, PointerInputPhase, PointerTarget},
     render::{Draw, RenderPhase},
 };
 use crate::{
@@ -20,12 +29,27 @@ use crate::{
     phase::{LayoutContext, Measurable, RenderContext},
 };
 
+thread_local! {
+    /// Areas of the surface covered by dropped nodes, which stay visible until
+    /// they are drawn over.
+    static DISPOSED: RefCell<Vec<Rect2D>> = RefCell::default();
+}
+
+/// Returns the areas covered by the nodes dropped since the last call.
+pub(crate) fn take_disposed() -> Vec<Rect2D> {
+    DISPOSED.take()
+}
+
 #[derive(Debug)]
 pub enum NodePhase {
     Virtual, // Does nothing itself
     Measurement(MeasurementPhase),
     MeasurementCompose(MeasurementComposePhase),
     Render(RenderPhase),
+    KeyInput(KeyInputPhase),
+    Focus(FocusPhase),
+    PointerInput(PointerInputPhase),
+    Layer(LayerPhase),
 }
 
 #[derive(Debug)]
@@ -34,6 +58,18 @@ pub struct Node {
     pub phase: NodePhase,
     pub layout_dirty: Cell<bool>,
     pub render_dirty: Cell<bool>,
+    /// Whether the node or one of its descendants has to be drawn again, as
+    /// of the last check before drawing.
+    pub subtree_render_dirty: Cell<bool>,
+    /// Area of the surface covered by the node and its descendants, as of the
+    /// last check before drawing.
+    pub bounds: Cell<Rect2D>,
+    /// Drawing of the node and its subtree with the area it was drawn at,
+    /// replayed while nothing in the subtree is render-dirty or moved.
+    pub picture: RefCell<Option<(Rect2D, Picture)>>,
+    /// Set when the node was drawn straight to the surface, so the picture
+    /// misses the changes of its descendants drawn since.
+    pub picture_stale: Cell<bool>,
 }
 
 impl fmt::Display for Node {
@@ -48,6 +84,10 @@ impl fmt::Display for Node {
                 NodePhase::Measurement(_) => "layout",
                 NodePhase::MeasurementCompose(_) => "layout + subcompose",
                 NodePhase::Render(_) => "render",
+                NodePhase::KeyInput(_) => "key input",
+                NodePhase::Focus(_) => "focus",
+                NodePhase::PointerInput(_) => "pointer input",
+                NodePhase::Layer(_) => "layer",
             },
             area.size.width,
             area.size.height,
@@ -64,25 +104,121 @@ impl Node {
             phase,
             layout_dirty: true.into(),
             render_dirty: true.into(),
+            subtree_render_dirty: true.into(),
+            bounds: Cell::default(),
+            picture: RefCell::default(),
+            picture_stale: Cell::default(),
         }
     }
 
     pub fn render(&self, context: &RenderContext) {
-        if self.render_dirty.get() {
-            if let NodePhase::Render(node) = &self.phase {
-                (node.render)(context);
+        if let NodePhase::Focus(node) = &self.phase {
+            node.target.set_area(*self.area.borrow());
+        }
+
+        let rumpose_context = context.rumpose_context();
+        let recording = rumpose_context.is_recording();
+
+        if !context.is_render_dirty() && !self.picture_stale.get() {
+            // Clean subtrees outside the damage keep what the surface shows.
+            if recording || rumpose_context.is_damaged(self.bounds.get()) {
+                self.draw_picture(context);
             }
 
-            if let NodePhase::MeasurementCompose(node) = &self.phase {
-                node.context.render();
-            } else if context.does_content_draw() {
-                context.draw_content();
+            return;
+        }
+
+        if !recording && !self.needs_render() {
+            // Only descendants changed, so drawing straight to the surface lets
+            // the clean ones outside the damage be skipped.
+            if context.is_render_dirty() || rumpose_context.is_damaged(self.bounds.get()) {
+                self.draw_node(context);
+                self.picture_stale.set(true);
+                self.subtree_render_dirty.set(false);
             }
 
-            self.render_dirty.set(false);
+            return;
+        }
+
+        let picture = rumpose_context.record(|| self.draw_node(context));
+
+        self.picture.replace(Some((*self.area.borrow(), picture)));
+        self.render_dirty.set(false);
+        self.subtree_render_dirty.set(false);
+        self.picture_stale.set(false);
+
+        self.draw_picture(context);
+    }
+
+    fn draw_picture(&self, context: &RenderContext) {
+        if let Some((_, picture)) = self.picture.borrow().as_ref() {
+            context.with_canvas(|canvas| canvas.draw_picture(picture, None, None));
         }
     }
 
+    fn draw_node(&self, context: &RenderContext) {
+        if let NodePhase::Render(node) = &self.phase {
+            (node.render)(context);
+        }
+
+        if let NodePhase::MeasurementCompose(node) = &self.phase {
+            node.context.render();
+        } else if let NodePhase::Layer(node) = &self.phase {
+            let area = *self.area.borrow();
+            let matrix = node.props.matrix(area);
+
+            context.with_canvas(|canvas| {
+                canvas.save();
+                canvas.concat(&matrix);
+
+                if let Some(clip) = &node.props.clip {
+                    canvas.clip_path(&clip(area), Some(ClipOp::Intersect), Some(true));
+                }
+            });
+
+            context.draw_content();
+
+            context.with_canvas(|canvas| canvas.restore());
+
+            node.drawn.set(Some(matrix));
+        } else if context.does_content_draw() {
+            context.draw_content();
+        }
+    }
+
+    /// Returns `true` if the node itself, ignoring its children, has to be
+    /// drawn again.
+    pub(crate) fn needs_render(&self) -> bool {
+        self.render_dirty.get()
+            || self
+                .picture
+                .borrow()
+                .as_ref()
+                .is_none_or(|(area, _)| *area != *self.area.borrow())
+            || matches!(
+                &self.phase,
+                NodePhase::MeasurementCompose(node) if node.context.is_render_dirty()
+            )
+    }
+
+    /// Returns the areas the node covered when last drawn and covers now, if
+    /// it has to be drawn again, plus the damage inside its subcomposition.
+    pub(crate) fn damage(&self) -> Vec<Rect2D> {
+        let area = *self.area.borrow();
+
+        let mut damage = match self.picture.borrow().as_ref() {
+            Some((recorded, _)) if *recorded != area => vec![*recorded, area],
+            Some(_) if !self.render_dirty.get() => Vec::new(),
+            _ => vec![area],
+        };
+
+        if let NodePhase::MeasurementCompose(node) = &self.phase {
+            damage.extend(node.context.damage());
+        }
+
+        damage
+    }
+
     pub fn mark_dirty(&self) {
         self.layout_dirty.set(true);
         self.render_dirty.set(true);
@@ -93,6 +229,14 @@ impl Node {
     }
 }
 
+impl Drop for Node {
+    fn drop(&mut self) {
+        if self.picture.get_mut().is_some() {
+            DISPOSED.with_borrow_mut(|disposed| disposed.push(self.bounds.get()));
+        }
+    }
+}
+
 impl ComposeNode for Node {
     type Context = RumposeContext;
 }
@@ -105,9 +249,17 @@ impl Measurable for Node {
         constraints: Constraints,
     ) -> Size2D {
         if self.layout_dirty.get() {
+            // Children are placed relative to the node, which its parent
+            // places afterwards.
+            self.area.borrow_mut().origin = Point2D::default();
+
             let size = match &self.phase {
                 NodePhase::Virtual => Size2D::default(),
-                NodePhase::Render(_) => context.measure(node.children[0], constraints),
+                NodePhase::Render(_)
+                | NodePhase::KeyInput(_)
+                | NodePhase::Focus(_)
+                | NodePhase::PointerInput(_)
+                | NodePhase::Layer(_) => context.measure(node.children[0], constraints),
                 NodePhase::MeasurementCompose(measure_node) => {
                     measure_node.measure(node, context, constraints)
                 }
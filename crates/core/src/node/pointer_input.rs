@@ -0,0 +1,71 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    rc::Rc,
+};
+
+use rumpose_engine::{Matrix, Point};
+use rumpose_layout::{Point2D, Rect2D};
+
+use crate::input::{PointerEvent, PointerEventPass};
+
+/// Handles a pointer event in one of its passes.
+pub type PointerHandler = Rc<dyn Fn(&PointerEvent, PointerEventPass)>;
+
+/// Receiver of the pointer events of a
+/// [`pointer_input`](crate::components::pointer_input) node. It outlives
+/// handler changes, so pointers pressed on or hovering the node keep reaching
+/// it across recompositions.
+pub struct PointerTarget {
+    handler: RefCell<PointerHandler>,
+    /// Maps tree coordinates to the untransformed coordinates of the node.
+    to_local: Cell<Matrix>,
+    area: Cell<Rect2D>,
+}
+
+impl PointerTarget {
+    fn new(handler: PointerHandler) -> Self {
+        Self {
+            handler: RefCell::new(handler),
+            to_local: Cell::new(Matrix::new_identity()),
+            area: Cell::default(),
+        }
+    }
+
+    /// Records where the node was found by the last hit test.
+    pub(crate) fn update(&self, to_local: Matrix, area: Rect2D) {
+        self.to_local.set(to_local);
+        self.area.set(area);
+    }
+
+    pub(crate) fn deliver(&self, event: &PointerEvent, pass: PointerEventPass) {
+        let area = self.area.get();
+        let position = Point2D::from(self.to_local.get().map_point(Point::from(event.position)));
+        let handler = self.handler.borrow().clone();
+
+        handler(&event.localized(position - area.origin, area.size), pass);
+    }
+}
+
+#[derive(Clone)]
+pub struct PointerInputPhase {
+    pub(crate) target: Rc<PointerTarget>,
+}
+
+impl PointerInputPhase {
+    pub fn new<F: Fn(&PointerEvent, PointerEventPass) + 'static>(handler: F) -> Self {
+        Self {
+            target: Rc::new(PointerTarget::new(Rc::new(handler))),
+        }
+    }
+
+    pub fn set_handler(&self, handler: PointerHandler) {
+        self.target.handler.replace(handler);
+    }
+}
+
+impl fmt::Debug for PointerInputPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PointerInputNode").finish_non_exhaustive()
+    }
+}
@@ -1,9 +1,10 @@
-use std::cell::Ref;
+use rumpose_engine::FontCollection;
+use rumpose_layout::{Constraints, Density, Size2D};
 
-use rumpose_engine::{FontCollection, Surface};
-use rumpose_layout::{Constraints, Size2D};
-
-use crate::{Composer, ComposerExt, RuntimeNode, node::NodeExt};
+use crate::{
+    Composer, ComposerExt, FontRegistry, RumposeContext, RuntimeNode,
+    node::{NodeExt, NodePhase},
+};
 
 #[derive(Clone, Copy)]
 pub struct LayoutContext<'a> {
@@ -15,32 +16,39 @@ impl<'a> LayoutContext<'a> {
         Self { composer }
     }
 
-    pub(crate) fn surface(&self) -> Ref<Surface> {
-        self.composer.context.surface.borrow()
+    pub(crate) const fn rumpose_context(&self) -> &RumposeContext {
+        &self.composer.context
     }
 
     #[must_use]
     pub const fn font_manager(&self) -> &FontCollection {
-        &self.composer.context.font_manager
+        self.composer.context.fonts.collection()
+    }
+
+    #[must_use]
+    pub const fn fonts(&self) -> &FontRegistry {
+        &self.composer.context.fonts
+    }
+
+    #[must_use]
+    pub fn density(&self) -> Density {
+        self.composer.context.density()
     }
 
     pub fn mark_dirty(&self, id: usize) {
         self.composer.mark_dirty(id);
     }
 
+    /// Moves the node to `(x, y)`, along with its subtree and the nodes it
+    /// subcomposed.
     pub fn place(&self, id: usize, x: f32, y: f32) {
-        let node = &self.composer.nodes[id];
-        let origin = node.get_area().origin;
+        let origin = self.composer.nodes[id].get_area().origin;
 
-        for &id in &node.children {
-            let offset = self.composer.nodes[id].get_area().origin - origin;
-
-            self.place(id, x + offset.x, y + offset.y);
-        }
-
-        node.place(x, y);
+        self.place_relative(id, x - origin.x, y - origin.y);
     }
 
+    /// Moves the node by `(x, y)`, along with its subtree and the nodes it
+    /// subcomposed.
     pub fn place_relative(&self, id: usize, x: f32, y: f32) {
         let node = &self.composer.nodes[id];
 
@@ -48,14 +56,26 @@ impl<'a> LayoutContext<'a> {
             self.place_relative(id, x, y);
         }
 
+        if let Some(NodePhase::MeasurementCompose(phase)) =
+            node.data.as_ref().map(|data| &data.phase)
+        {
+            phase.context.translate(x, y);
+        }
+
         node.place_relative(x, y);
     }
 
+    /// Measures the node and moves it to the origin, from where its parent
+    /// places it. Clean nodes are not measured again, but keep the position of
+    /// the previous layout until then.
     #[must_use]
     pub fn measure(&self, id: usize, constraints: Constraints) -> Size2D {
         let node = &self.composer.nodes[id];
+        let size = node.measure(*self, constraints);
+
+        self.place(id, 0., 0.);
 
-        node.measure(*self, constraints)
+        size
     }
 }
 
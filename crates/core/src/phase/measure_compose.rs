@@ -1,15 +1,14 @@
-use std::{
-    cell::RefCell,
-    collections::HashMap,
-    rc::Rc,
-};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
-use rumpose_layout::{Constraints, Size2D};
+use rumpose_engine::Matrix;
+use rumpose_layout::{Constraints, Point2D, Rect2D, Size2D};
 
 use super::LayoutContext;
 use crate::{
-    Composer, ComposerExt, Recomposer, RumposeContext, RuntimeNode, Scope,
-    node::{Node, NodeExt, NodePhase},
+    Composer, ComposerExt, Recomposer, RuntimeNode, Scope, draw_root, focus_path,
+    input::hit_test,
+    node::{KeyInputPhase, Node, NodePhase, PointerTarget},
+    propagate_render_dirty, union_bounds,
 };
 
 #[derive(Clone, Default)]
@@ -24,11 +23,88 @@ impl SubcomposeContext {
 
     #[track_caller]
     pub fn render(&self) {
-        for recomposer in (*self.recomposers.borrow_mut()).values_mut() {
-            recomposer.with_composer_mut(|composer| composer.draw_all());
+        for recomposer in (*self.recomposers.borrow()).values() {
+            recomposer.with_composer(draw_root);
+        }
+    }
+
+    #[track_caller]
+    pub fn mark_dirty(&self) {
+        for recomposer in (*self.recomposers.borrow()).values() {
+            recomposer.with_composer(|composer| {
+                composer.mark_dirty(composer.root_node_key());
+            });
         }
     }
 
+    #[track_caller]
+    pub fn mark_render_dirty(&self) {
+        for recomposer in (*self.recomposers.borrow()).values() {
+            recomposer.with_composer(|composer| {
+                composer.mark_render_dirty(composer.root_node_key());
+            });
+        }
+    }
+
+    #[track_caller]
+    pub fn is_render_dirty(&self) -> bool {
+        (*self.recomposers.borrow()).values().any(|recomposer| {
+            recomposer.with_composer(|composer| composer.is_render_dirty(composer.root_node_key()))
+        })
+    }
+
+    /// Caches the render dirtiness and covered areas of the subcompositions,
+    /// see [`propagate_render_dirty`](crate::propagate_render_dirty).
+    pub(crate) fn propagate_render_dirty(
+        &self,
+        matrix: &Matrix,
+        disposed: &[Rect2D],
+    ) -> (bool, Option<Rect2D>) {
+        (*self.recomposers.borrow())
+            .values()
+            .fold((false, None), |(dirty, bounds), recomposer| {
+                let (child_dirty, child_bounds) = recomposer.with_composer(|composer| {
+                    propagate_render_dirty(composer, composer.root_node_key(), matrix, disposed)
+                });
+
+                (dirty || child_dirty, union_bounds(bounds, child_bounds))
+            })
+    }
+
+    #[track_caller]
+    #[must_use]
+    pub fn damage(&self) -> Vec<Rect2D> {
+        (*self.recomposers.borrow())
+            .values()
+            .flat_map(|recomposer| {
+                recomposer.with_composer(|composer| composer.damage(composer.root_node_key()))
+            })
+            .collect()
+    }
+
+    /// Collects the key input nodes on the way to the focused node in the
+    /// subcompositions, see [`focus_path`](crate::focus_path).
+    pub(crate) fn focus_path(&self, path: &mut Vec<KeyInputPhase>) -> bool {
+        (*self.recomposers.borrow()).values().any(|recomposer| {
+            recomposer
+                .with_composer(|composer| focus_path(composer, composer.root_node_key(), path))
+        })
+    }
+
+    /// Hit tests the subcompositions, see [`hit_test`](crate::input::hit_test).
+    pub(crate) fn hit_test(
+        &self,
+        position: Point2D,
+        to_local: Matrix,
+        hits: &mut Vec<Rc<PointerTarget>>,
+    ) -> bool {
+        (*self.recomposers.borrow()).values().any(|recomposer| {
+            recomposer.with_composer(|composer| {
+                hit_test(composer, composer.root_node_key(), position, to_local, hits)
+            })
+        })
+    }
+
     #[track_caller]
     pub fn print_tree(&self) {
         for recomposer in (*self.recomposers.borrow()).values() {
@@ -59,11 +135,7 @@ impl SubcomposeContext {
                         |_, _, _| {},
                     );
                 },
-                RumposeContext {
-                    root: constraints,
-                    surface: RefCell::new(context.surface().clone()),
-                    font_manager: context.font_manager().clone(),
-                },
+                context.rumpose_context().subcontext(constraints),
             )
         });
 
@@ -78,7 +150,7 @@ impl SubcomposeContext {
 
             for value in composer.nodes[root].children.clone() {
                 composer.mark_dirty(value);
-                
+
                 results.push(func(
                     LayoutContext::new(composer),
                     value,
@@ -90,39 +162,44 @@ impl SubcomposeContext {
         })
     }
 
+    /// Disposes the subcompositions whose key `keep` returns `false` for,
+    /// e.g. the items a lazy list scrolled away from.
+    pub fn retain(&self, mut keep: impl FnMut(usize) -> bool) {
+        self.recomposers.borrow_mut().retain(|&key, _| keep(key));
+    }
+
+    /// Moves a node of the subcomposition `key` to `(x, y)`, see
+    /// [`LayoutContext::place`].
     pub fn place(&self, key: usize, id: usize, x: f32, y: f32) {
         self.recomposers
             .borrow()
             .get(&key)
             .unwrap()
-            .with_composer(|composer| {
-                let node = &composer.nodes[id];
-                let origin = node.get_area().origin;
-
-                for &id in &node.children {
-                    let offset = composer.nodes[id].get_area().origin - origin;
-
-                    self.place(key, id, x + offset.x, y + offset.y);
-                }
-
-                node.place(x, y);
-            })
+            .with_composer(|composer| LayoutContext::new(composer).place(id, x, y));
     }
 
+    /// Moves a node of the subcomposition `key` by `(x, y)`, see
+    /// [`LayoutContext::place_relative`].
     pub fn place_relative(&self, key: usize, id: usize, x: f32, y: f32) {
         self.recomposers
             .borrow()
             .get(&key)
             .unwrap()
-            .with_composer(|composer| {
-                let node = &composer.nodes[id];
+            .with_composer(|composer| LayoutContext::new(composer).place_relative(id, x, y));
+    }
 
-                for &id in &node.children {
-                    self.place_relative(key, id, x, y);
-                }
+    /// Moves every subcomposed node by `(x, y)`, when the node owning the
+    /// subcompositions is placed.
+    pub(crate) fn translate(&self, x: f32, y: f32) {
+        for recomposer in (*self.recomposers.borrow()).values() {
+            recomposer.with_composer(|composer| {
+                let context = LayoutContext::new(composer);
 
-                node.place_relative(x, y);
-            })
+                for &id in &composer.nodes[composer.root_node_key()].children {
+                    context.place_relative(id, x, y);
+                }
+            });
+        }
     }
 
     #[must_use]
@@ -131,10 +208,6 @@ impl SubcomposeContext {
             .borrow()
             .get(&key)
             .unwrap()
-            .with_composer(|composer| {
-                let node = &composer.nodes[id];
-
-                node.measure(LayoutContext::new(composer), constraints)
-            })
+            .with_composer(|composer| LayoutContext::new(composer).measure(id, constraints))
     }
 }
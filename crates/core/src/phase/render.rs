@@ -1,9 +1,11 @@
 use std::cell::{Cell, RefMut};
 
-use rumpose_engine::{FontCollection, Surface};
-use rumpose_layout::Rect2D;
+use rumpose_engine::{Canvas, FontCollection, SkiaCanvas, Surface, graphics};
+use rumpose_layout::{Density, Rect2D};
 
-use crate::{Composer, RuntimeNode, node::NodeExt};
+use crate::{
+    Composer, ComposerExt, FontRegistry, RumposeContext, RuntimeNode, draw_order, node::NodeExt,
+};
 
 #[derive(Clone)]
 pub struct RenderContext<'a> {
@@ -28,14 +30,80 @@ impl<'a> RenderContext<'a> {
 
     #[must_use]
     pub const fn font_manager(&self) -> &FontCollection {
-        &self.composer.context.font_manager
+        self.composer.context.fonts.collection()
     }
 
     #[must_use]
-    pub fn surface(&self) -> RefMut<Surface> {
+    pub const fn fonts(&self) -> &FontRegistry {
+        &self.composer.context.fonts
+    }
+
+    pub(crate) const fn rumpose_context(&self) -> &RumposeContext {
+        &self.composer.context
+    }
+
+    /// Returns `true` if the node or any of its descendants has to be drawn
+    /// again.
+    #[must_use]
+    pub fn is_render_dirty(&self) -> bool {
+        match self.node.data.as_ref() {
+            Some(data) => data.subtree_render_dirty.get(),
+            None => self
+                .node
+                .children
+                .iter()
+                .any(|&id| self.composer.is_render_dirty(id)),
+        }
+    }
+
+    #[must_use]
+    pub fn density(&self) -> Density {
+        self.composer.context.density()
+    }
+
+    /// Raster surface of the context. Drawing goes through
+    /// [`with_canvas`](RenderContext::with_canvas), which also draws into
+    /// active recordings.
+    pub(crate) fn surface(&self) -> RefMut<Surface> {
         self.composer.context.surface.borrow_mut()
     }
 
+    /// Calls `draw` with the canvas the tree is currently drawn to: the
+    /// canvas of the innermost recording, e.g. the one caching the picture of
+    /// the node being drawn, the surface canvas otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called again from inside `draw`.
+    pub fn with_canvas<R>(&self, draw: impl FnOnce(&Canvas) -> R) -> R {
+        if let Some(recorder) = self.composer.context.recorders.borrow_mut().last_mut() {
+            if let Some(canvas) = recorder.recording_canvas() {
+                return draw(canvas);
+            }
+        }
+
+        draw(self.surface().canvas())
+    }
+
+    /// Same as [`with_canvas`](RenderContext::with_canvas), but through the
+    /// backend independent [`graphics::Canvas`] interface. Text is drawn with
+    /// the default family of the registry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called again from inside `draw`.
+    pub fn with_graphics<R>(&self, draw: impl FnOnce(&mut dyn graphics::Canvas) -> R) -> R {
+        let fonts = self.fonts();
+
+        self.with_canvas(|canvas| {
+            draw(&mut SkiaCanvas::new(
+                canvas,
+                fonts.collection(),
+                fonts.default_family(),
+            ))
+        })
+    }
+
     pub fn set_do_content_draw(&self, value: bool) {
         self.draw_content.set(value);
     }
@@ -44,8 +112,9 @@ impl<'a> RenderContext<'a> {
         self.draw_content.get()
     }
 
+    /// Draws the children of the node, by increasing z-index.
     pub fn draw_content(&self) {
-        for &node in &self.node.children {
+        for node in draw_order(self.composer, self.node) {
             let node = &self.composer.nodes[node];
 
             node.draw(RenderContext {
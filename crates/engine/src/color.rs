@@ -0,0 +1,23 @@
+use rumpose_graphics::{Color, ColorSpace};
+use skia_safe::Color4f;
+
+/// Conversions of [`Color`] into skia colors, which are always sRGB.
+pub trait ColorExt {
+    /// Converts to an 8-bit color, clamping it to the sRGB gamut.
+    fn to_engine(self) -> skia_safe::Color;
+    /// Converts to a float color, keeping components outside of the sRGB
+    /// gamut for wide gamut surfaces.
+    fn to_engine4f(self) -> Color4f;
+}
+
+impl ColorExt for Color {
+    fn to_engine(self) -> skia_safe::Color {
+        skia_safe::Color::new(u32::from(self))
+    }
+
+    fn to_engine4f(self) -> Color4f {
+        let color = self.convert(ColorSpace::Srgb);
+
+        Color4f::new(color.red, color.green, color.blue, color.alpha)
+    }
+}
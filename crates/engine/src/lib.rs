@@ -1,5 +1,30 @@
+mod color;
+mod renderer;
+
+pub use rumpose_graphics as graphics;
 pub use skia_safe::{
-    Canvas, ClipOp, Color, EncodedImageFormat, Font, FontMgr as FontManager, FontStyle, Image,
-    Paint, Path, PathBuilder, Point, RRect, Rect, Size, Surface, font_style::Weight as FontWeight,
-    surfaces::raster_n32_premul as create_surface, textlayout::*,
+    AlphaType, BlendMode, Canvas, ClipOp, Color as EngineColor, ColorFilter, ColorType, Data,
+    EncodedImageFormat, Font, FontArguments, FontMgr as FontManager, FontStyle, FourByteTag, IRect,
+    Image, ImageInfo, Matrix, Paint, PaintCap, PaintJoin, Path, PathBuilder, Picture,
+    PictureRecorder, Point, RRect, Rect, Shader, Size, Surface, TileMode, Typeface,
+    canvas::SaveLayerRec,
+    color_filters,
+    font_arguments::{VariationPosition, variation_position::Coordinate as VariationCoordinate},
+    font_style::{Slant as FontSlant, Weight as FontWeight},
+    pdf,
+    surfaces::raster_n32_premul as create_surface,
+    svg::{Canvas as SvgCanvas, Dom as SvgDom},
+    textlayout::*,
+};
+
+/// DejaVu Sans, embedded with the `bundled-font` feature so text renders the
+/// same on every host. Its license is in `fonts/LICENSE`.
+#[cfg(feature = "bundled-font")]
+pub const BUNDLED_FONT: &[u8] = include_bytes!("../fonts/DejaVuSans.ttf");
+#[cfg(feature = "bundled-font")]
+pub const BUNDLED_FONT_FAMILY: &str = "DejaVu Sans";
+
+pub use self::{
+    color::ColorExt,
+    renderer::{SkiaCanvas, SkiaRenderer, to_graphics_path},
 };
@@ -0,0 +1,284 @@
+use rumpose_graphics::{
+    Canvas, Color, ImageData, Paint, PaintStyle, Path, PathCommand, Rect, Renderer,
+};
+use skia_safe::{
+    AlphaType, ColorType, Data, Font, FontMgr, FontStyle, ImageInfo, Point, Surface, Typeface,
+    images, path::Verb, textlayout::FontCollection,
+};
+
+use crate::ColorExt;
+
+fn to_rect(rect: Rect) -> skia_safe::Rect {
+    skia_safe::Rect::from_xywh(rect.x, rect.y, rect.width, rect.height)
+}
+
+fn to_paint(paint: &Paint) -> skia_safe::Paint {
+    let mut result = skia_safe::Paint::default();
+
+    result.set_color4f(paint.color.to_engine4f(), None);
+    result.set_anti_alias(paint.anti_alias);
+
+    if let PaintStyle::Stroke { width } = paint.style {
+        result.set_stroke(true);
+        result.set_stroke_width(width);
+    }
+
+    result
+}
+
+fn to_path(path: &Path) -> skia_safe::Path {
+    let mut result = skia_safe::Path::new();
+
+    for command in path.commands() {
+        match *command {
+            PathCommand::MoveTo(x, y) => result.move_to((x, y)),
+            PathCommand::LineTo(x, y) => result.line_to((x, y)),
+            PathCommand::QuadTo(x1, y1, x2, y2) => result.quad_to((x1, y1), (x2, y2)),
+            PathCommand::CubicTo(x1, y1, x2, y2, x3, y3) => {
+                result.cubic_to((x1, y1), (x2, y2), (x3, y3))
+            }
+            PathCommand::Close => result.close(),
+        };
+    }
+
+    result
+}
+
+/// Converts a skia path into a backend independent one. Conics, which have no
+/// [`PathCommand`], are approximated by two quads each.
+#[must_use]
+pub fn to_graphics_path(path: &skia_safe::Path) -> Path {
+    let mut iter = skia_safe::path::Iter::new(path, false);
+    let mut result = Path::new();
+
+    while let Some((verb, points)) = iter.next() {
+        result = match verb {
+            Verb::Move => result.move_to(points[0].x, points[0].y),
+            Verb::Line => result.line_to(points[1].x, points[1].y),
+            Verb::Quad => result.quad_to(points[1].x, points[1].y, points[2].x, points[2].y),
+            Verb::Conic => {
+                let mut quads = [Point::default(); 5];
+                let count = skia_safe::Path::convert_conic_to_quads(
+                    points[0],
+                    points[1],
+                    points[2],
+                    iter.conic_weight().unwrap_or(1.),
+                    &mut quads,
+                    1,
+                )
+                .unwrap_or(0);
+
+                quads[1..=count * 2]
+                    .chunks_exact(2)
+                    .fold(result, |result, quad| {
+                        result.quad_to(quad[0].x, quad[0].y, quad[1].x, quad[1].y)
+                    })
+            }
+            Verb::Cubic => result.cubic_to(
+                points[1].x,
+                points[1].y,
+                points[2].x,
+                points[2].y,
+                points[3].x,
+                points[3].y,
+            ),
+            Verb::Close => result.close(),
+            Verb::Done => break,
+        };
+    }
+
+    result
+}
+
+/// [`Canvas`] implementation drawing to a skia canvas. Text is drawn with the
+/// given family, looked up in the font collection.
+pub struct SkiaCanvas<'a> {
+    canvas: &'a skia_safe::Canvas,
+    fonts: FontCollection,
+    family: &'a str,
+}
+
+impl<'a> SkiaCanvas<'a> {
+    #[must_use]
+    pub fn new(canvas: &'a skia_safe::Canvas, fonts: &FontCollection, family: &'a str) -> Self {
+        Self {
+            canvas,
+            fonts: fonts.clone(),
+            family,
+        }
+    }
+
+    fn typeface(&mut self) -> Option<Typeface> {
+        self.fonts
+            .find_typefaces(&[self.family], FontStyle::default())
+            .into_iter()
+            .next()
+            .or_else(|| self.fonts.default_fallback())
+    }
+}
+
+impl Canvas for SkiaCanvas<'_> {
+    fn save(&mut self) {
+        self.canvas.save();
+    }
+
+    fn save_layer(&mut self, alpha: f32) {
+        self.canvas.save_layer_alpha_f(None, alpha);
+    }
+
+    fn restore(&mut self) {
+        self.canvas.restore();
+    }
+
+    fn translate(&mut self, dx: f32, dy: f32) {
+        self.canvas.translate((dx, dy));
+    }
+
+    fn scale(&mut self, sx: f32, sy: f32) {
+        self.canvas.scale((sx, sy));
+    }
+
+    fn rotate(&mut self, degrees: f32) {
+        self.canvas.rotate(degrees, None);
+    }
+
+    fn clip_rect(&mut self, rect: Rect) {
+        self.canvas.clip_rect(to_rect(rect), None, Some(true));
+    }
+
+    fn clip_path(&mut self, path: &Path) {
+        self.canvas.clip_path(&to_path(path), None, Some(true));
+    }
+
+    fn clear(&mut self, color: Color) {
+        self.canvas.clear(color.to_engine4f());
+    }
+
+    fn draw_rect(&mut self, rect: Rect, paint: &Paint) {
+        self.canvas.draw_rect(to_rect(rect), &to_paint(paint));
+    }
+
+    fn draw_round_rect(&mut self, rect: Rect, radius: f32, paint: &Paint) {
+        self.canvas
+            .draw_round_rect(to_rect(rect), radius, radius, &to_paint(paint));
+    }
+
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, paint: &Paint) {
+        self.canvas.draw_circle((x, y), radius, &to_paint(paint));
+    }
+
+    fn draw_path(&mut self, path: &Path, paint: &Paint) {
+        self.canvas.draw_path(&to_path(path), &to_paint(paint));
+    }
+
+    fn draw_image(&mut self, image: &ImageData, dst: Rect) {
+        let info = ImageInfo::new(
+            (image.width() as i32, image.height() as i32),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+
+        if let Some(image) =
+            images::raster_from_data(&info, Data::new_copy(image.pixels()), info.min_row_bytes())
+        {
+            let mut paint = skia_safe::Paint::default();
+
+            paint.set_anti_alias(true);
+
+            self.canvas
+                .draw_image_rect(image, None, to_rect(dst), &paint);
+        }
+    }
+
+    fn draw_text(&mut self, text: &str, x: f32, y: f32, font_size: f32, paint: &Paint) {
+        let Some(typeface) = self.typeface() else {
+            return;
+        };
+
+        self.canvas.draw_str(
+            text,
+            (x, y),
+            &Font::from_typeface(typeface, font_size),
+            &to_paint(paint),
+        );
+    }
+}
+
+/// [`Renderer`] drawing to a CPU raster skia surface. Text uses the system
+/// fonts unless another collection is set with
+/// [`with_fonts`](SkiaRenderer::with_fonts).
+pub struct SkiaRenderer {
+    surface: Surface,
+    fonts: FontCollection,
+    family: String,
+}
+
+impl SkiaRenderer {
+    /// # Panics
+    ///
+    /// Panics if the surface cannot be created, e.g. when either dimension is
+    /// zero.
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        let mut fonts = FontCollection::new();
+
+        fonts.set_default_font_manager(FontMgr::new(), None);
+
+        Self {
+            surface: Self::create_surface(width, height),
+            fonts,
+            family: String::new(),
+        }
+    }
+
+    /// Draws text with `family` from `fonts`, falling back to the default
+    /// font of the collection.
+    #[must_use]
+    pub fn with_fonts(mut self, fonts: FontCollection, family: impl Into<String>) -> Self {
+        self.fonts = fonts;
+        self.family = family.into();
+
+        self
+    }
+
+    fn create_surface(width: u32, height: u32) -> Surface {
+        skia_safe::surfaces::raster_n32_premul((width as i32, height as i32))
+            .expect("failed to create surface")
+    }
+
+    pub fn surface(&mut self) -> &mut Surface {
+        &mut self.surface
+    }
+}
+
+impl Renderer for SkiaRenderer {
+    fn size(&self) -> (u32, u32) {
+        (self.canvas.width() as u32, self.canvas.height() as u32)
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        self.surface = Self::create_surface(width, height);
+    }
+
+    fn with_canvas(&mut self, draw: &mut dyn FnMut(&mut dyn Canvas)) {
+        draw(&mut SkiaCanvas(self.canvas.canvas()));
+    }
+
+    fn read_rgba(&mut self) -> Vec<u8> {
+        let (width, height) = self.size();
+        let info = ImageInfo::new(
+            (width as i32, height as i32),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+        let row_bytes = info.min_row_bytes();
+        let mut pixels = vec![0; row_bytes * height as usize];
+
+        self.canvas
+            .read_pixels(&info, &mut pixels, row_bytes, (0, 0));
+
+        pixels
+    }
+}
@@ -105,6 +105,31 @@ impl<T: PartialOrd + Add<Output = T> + Copy> Rect<T> {
             && point.x <= self.max_x()
             && point.y <= self.max_y()
     }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min_x() < other.max_x()
+            && other.min_x() < self.max_x()
+            && self.min_y() < other.max_y()
+            && other.min_y() < self.max_y()
+    }
+}
+
+impl<T: PartialOrd + Add<Output = T> + Sub<Output = T> + Copy> Rect<T> {
+    /// Returns the smallest rectangle containing both rectangles.
+    pub fn union(&self, other: &Self) -> Self {
+        let min = |a: T, b: T| if a < b { a } else { b };
+        let max = |a: T, b: T| if a > b { a } else { b };
+
+        let x = min(self.min_x(), other.min_x());
+        let y = min(self.min_y(), other.min_y());
+
+        Self::from_xywh(
+            x,
+            y,
+            max(self.max_x(), other.max_x()) - x,
+            max(self.max_y(), other.max_y()) - y,
+        )
+    }
 }
 
 impl<T: Copy> Rect<T> {
@@ -355,3 +380,26 @@ impl From<Rect<f32>> for rumpose_engine::Rect {
         )
     }
 }
+
+impl From<Rect<f32>> for rumpose_engine::graphics::Rect {
+    fn from(value: Rect<f32>) -> Self {
+        Self::new(
+            value.origin.x,
+            value.origin.y,
+            value.size.width,
+            value.size.height,
+        )
+    }
+}
+
+impl From<rumpose_engine::Point> for Point<f32> {
+    fn from(value: rumpose_engine::Point) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl From<rumpose_engine::Rect> for Rect<f32> {
+    fn from(value: rumpose_engine::Rect) -> Self {
+        Self::from_xywh(value.left, value.top, value.width(), value.height())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{Color, ImageData, Paint, Path, Rect};
+
+/// Drawing operations every rendering backend provides. Coordinates are in
+/// pixels and go through the current transformation.
+pub trait Canvas {
+    fn save(&mut self);
+    /// Saves the state and draws everything until the matching
+    /// [`restore`](Canvas::restore) into an offscreen layer, which is then
+    /// composited with the given opacity.
+    fn save_layer(&mut self, alpha: f32);
+    fn restore(&mut self);
+
+    fn translate(&mut self, dx: f32, dy: f32);
+    fn scale(&mut self, sx: f32, sy: f32);
+    /// Rotates clockwise around the current origin.
+    fn rotate(&mut self, degrees: f32);
+
+    fn clip_rect(&mut self, rect: Rect);
+    fn clip_path(&mut self, path: &Path);
+
+    fn clear(&mut self, color: Color);
+    fn draw_rect(&mut self, rect: Rect, paint: &Paint);
+    fn draw_round_rect(&mut self, rect: Rect, radius: f32, paint: &Paint);
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, paint: &Paint);
+    fn draw_path(&mut self, path: &Path, paint: &Paint);
+    fn draw_image(&mut self, image: &ImageData, dst: Rect);
+    /// Draws a single line of text with its baseline starting at `(x, y)`.
+    fn draw_text(&mut self, text: &str, x: f32, y: f32, font_size: f32, paint: &Paint);
+}
+
+/// Render target owning the pixels a [`Canvas`] draws to.
+pub trait Renderer {
+    fn size(&self) -> (u32, u32);
+    /// Reallocates the target, discarding its contents.
+    fn resize(&mut self, width: u32, height: u32);
+    fn with_canvas(&mut self, draw: &mut dyn FnMut(&mut dyn Canvas));
+    /// Returns unpremultiplied RGBA pixels, row by row.
+    fn read_rgba(&mut self) -> Vec<u8>;
+}
@@ -0,0 +1,550 @@
+use std::{error::Error, fmt, str::FromStr};
+
+type Matrix = [[f32; 3]; 3];
+
+const LINEAR_P3_TO_SRGB: Matrix = [
+    [1.224_940_1, -0.224_940_4, 0.],
+    [-0.042_056_9, 1.042_057_1, 0.],
+    [-0.019_637_6, -0.078_636_1, 1.098_273_5],
+];
+
+const LINEAR_SRGB_TO_P3: Matrix = [
+    [0.822_462_1, 0.177_538, 0.],
+    [0.033_194_1, 0.966_805_8, 0.],
+    [0.017_082_7, 0.072_397_4, 0.910_519_9],
+];
+
+const LINEAR_SRGB_TO_LMS: Matrix = [
+    [0.412_221_47, 0.536_332_54, 0.051_445_993],
+    [0.211_903_5, 0.680_699_5, 0.107_396_96],
+    [0.088_302_46, 0.281_718_84, 0.629_978_7],
+];
+
+const LMS_TO_OKLAB: Matrix = [
+    [0.210_454_26, 0.793_617_8, -0.004_072_047],
+    [1.977_998_5, -2.428_592_2, 0.450_593_7],
+    [0.025_904_037, 0.782_771_77, -0.808_675_77],
+];
+
+const OKLAB_TO_LMS: Matrix = [
+    [1., 0.396_337_78, 0.215_803_76],
+    [1., -0.105_561_346, -0.063_854_17],
+    [1., -0.089_484_18, -1.291_485_5],
+];
+
+const LMS_TO_LINEAR_SRGB: Matrix = [
+    [4.076_741_7, -3.307_711_6, 0.230_969_93],
+    [-1.268_438, 2.609_757_4, -0.341_319_38],
+    [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+];
+
+fn transform(matrix: &Matrix, [x, y, z]: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * x + row[1] * y + row[2] * z)
+}
+
+/// sRGB transfer function, also used by Display P3, extended to negative
+/// values.
+fn decode(value: f32) -> f32 {
+    let abs = value.abs();
+
+    let linear = if abs <= 0.040_45 {
+        abs / 12.92
+    } else {
+        ((abs + 0.055) / 1.055).powf(2.4)
+    };
+
+    linear.copysign(value)
+}
+
+fn encode(value: f32) -> f32 {
+    let abs = value.abs();
+
+    let encoded = if abs <= 0.003_130_8 {
+        abs * 12.92
+    } else {
+        1.055 * abs.powf(1. / 2.4) - 0.055
+    };
+
+    encoded.copysign(value)
+}
+
+/// Returns the hue in degrees of an sRGB color with the given maximum
+/// component and chroma.
+fn hue([red, green, blue]: [f32; 3], max: f32, chroma: f32) -> f32 {
+    if chroma == 0. {
+        0.
+    } else if max == red {
+        60. * ((green - blue) / chroma).rem_euclid(6.)
+    } else if max == green {
+        60. * ((blue - red) / chroma + 2.)
+    } else {
+        60. * ((red - green) / chroma + 4.)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    #[default]
+    Srgb,
+    LinearSrgb,
+    DisplayP3,
+}
+
+impl ColorSpace {
+    const fn is_linear(self) -> bool {
+        matches!(self, Self::LinearSrgb)
+    }
+}
+
+/// Color with straight alpha and float components, nominally from 0 to 1,
+/// in the given color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+    pub alpha: f32,
+    pub space: ColorSpace,
+}
+
+impl Color {
+    pub const BLACK: Self = Self::new(0., 0., 0., 1.);
+    pub const BLUE: Self = Self::new(0., 0., 1., 1.);
+    pub const CYAN: Self = Self::new(0., 1., 1., 1.);
+    pub const DARK_GRAY: Self = Self::from_gray(0x44);
+    pub const GRAY: Self = Self::from_gray(0x88);
+    pub const GREEN: Self = Self::new(0., 1., 0., 1.);
+    pub const LIGHT_GRAY: Self = Self::from_gray(0xCC);
+    pub const MAGENTA: Self = Self::new(1., 0., 1., 1.);
+    pub const RED: Self = Self::new(1., 0., 0., 1.);
+    pub const TRANSPARENT: Self = Self::new(0., 0., 0., 0.);
+    pub const WHITE: Self = Self::new(1., 1., 1., 1.);
+    pub const YELLOW: Self = Self::new(1., 1., 0., 1.);
+
+    /// Creates an sRGB color.
+    #[must_use]
+    pub const fn new(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Self::new_in(ColorSpace::Srgb, red, green, blue, alpha)
+    }
+
+    #[must_use]
+    pub const fn new_in(space: ColorSpace, red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+            space,
+        }
+    }
+
+    const fn from_gray(value: u8) -> Self {
+        let value = value as f32 / 255.;
+
+        Self::new(value, value, value, 1.)
+    }
+
+    #[must_use]
+    pub const fn from_argb(alpha: u8, red: u8, green: u8, blue: u8) -> Self {
+        Self::new(
+            red as f32 / 255.,
+            green as f32 / 255.,
+            blue as f32 / 255.,
+            alpha as f32 / 255.,
+        )
+    }
+
+    #[must_use]
+    pub const fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::from_argb(255, red, green, blue)
+    }
+
+    /// Parses `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`, with the `#` being
+    /// optional.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseColorError`] if `value` is not in one of these forms.
+    pub fn from_hex(value: &str) -> Result<Self, ParseColorError> {
+        let digits = value.strip_prefix('#').unwrap_or(value);
+
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(ParseColorError(value.to_owned()));
+        }
+
+        let channel = |index: usize, width: usize| {
+            let channel = u8::from_str_radix(&digits[index * width..(index + 1) * width], 16)
+                .unwrap_or_default();
+
+            if width == 1 { channel * 0x11 } else { channel }
+        };
+
+        match digits.len() {
+            3 => Ok(Self::from_rgb(channel(0, 1), channel(1, 1), channel(2, 1))),
+            4 => Ok(Self::from_argb(
+                channel(3, 1),
+                channel(0, 1),
+                channel(1, 1),
+                channel(2, 1),
+            )),
+            6 => Ok(Self::from_rgb(channel(0, 2), channel(1, 2), channel(2, 2))),
+            8 => Ok(Self::from_argb(
+                channel(3, 2),
+                channel(0, 2),
+                channel(1, 2),
+                channel(2, 2),
+            )),
+            _ => Err(ParseColorError(value.to_owned())),
+        }
+    }
+
+    /// Creates an sRGB color from a hue in degrees and saturation and
+    /// lightness from 0 to 1.
+    #[must_use]
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let amount = saturation * lightness.min(1. - lightness);
+        let component = |offset: f32| {
+            let k = (offset + hue / 30.).rem_euclid(12.);
+
+            lightness - amount * (k - 3.).min(9. - k).clamp(-1., 1.)
+        };
+
+        Self::new(component(0.), component(8.), component(4.), alpha)
+    }
+
+    /// Creates an sRGB color from a hue in degrees and saturation and value
+    /// from 0 to 1.
+    #[must_use]
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+        let component = |offset: f32| {
+            let k = (offset + hue / 60.).rem_euclid(6.);
+
+            value - value * saturation * k.min(4. - k).clamp(0., 1.)
+        };
+
+        Self::new(component(5.), component(3.), component(1.), alpha)
+    }
+
+    /// Creates an sRGB color from OKLCH lightness from 0 to 1, chroma and hue
+    /// in degrees. Colors outside of the sRGB gamut keep out of range
+    /// components.
+    #[must_use]
+    pub fn from_oklch(lightness: f32, chroma: f32, hue: f32, alpha: f32) -> Self {
+        let hue = hue.to_radians();
+
+        Self::from_oklab([lightness, chroma * hue.cos(), chroma * hue.sin()], alpha)
+            .convert(ColorSpace::Srgb)
+    }
+
+    /// Creates a linear sRGB color from Oklab coordinates.
+    fn from_oklab(lab: [f32; 3], alpha: f32) -> Self {
+        let lms = transform(&OKLAB_TO_LMS, lab).map(|value| value.powi(3));
+        let [red, green, blue] = transform(&LMS_TO_LINEAR_SRGB, lms);
+
+        Self::new_in(ColorSpace::LinearSrgb, red, green, blue, alpha)
+    }
+
+    #[must_use]
+    pub const fn with_alpha(mut self, value: f32) -> Self {
+        self.alpha = value;
+
+        self
+    }
+
+    const fn components(&self) -> [f32; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    /// Converts the color into `space`. Components outside of the gamut of
+    /// `space` are kept, see [`clamp`](Color::clamp).
+    #[must_use]
+    pub fn convert(self, space: ColorSpace) -> Self {
+        if self.space == space {
+            return self;
+        }
+
+        let mut components = self.components();
+
+        if !self.space.is_linear() {
+            components = components.map(decode);
+        }
+
+        if self.space == ColorSpace::DisplayP3 {
+            components = transform(&LINEAR_P3_TO_SRGB, components);
+        }
+
+        if space == ColorSpace::DisplayP3 {
+            components = transform(&LINEAR_SRGB_TO_P3, components);
+        }
+
+        if !space.is_linear() {
+            components = components.map(encode);
+        }
+
+        let [red, green, blue] = components;
+
+        Self::new_in(space, red, green, blue, self.alpha)
+    }
+
+    /// Clamps every component to the range from 0 to 1.
+    #[must_use]
+    pub const fn clamp(self) -> Self {
+        Self::new_in(
+            self.space,
+            self.red.clamp(0., 1.),
+            self.green.clamp(0., 1.),
+            self.blue.clamp(0., 1.),
+            self.alpha.clamp(0., 1.),
+        )
+    }
+
+    /// Returns hue in degrees, saturation and lightness of the color in sRGB.
+    #[must_use]
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let components = self.convert(ColorSpace::Srgb).components();
+        let max = components.into_iter().fold(f32::MIN, f32::max);
+        let min = components.into_iter().fold(f32::MAX, f32::min);
+        let chroma = max - min;
+        let lightness = (max + min) / 2.;
+
+        let saturation = if chroma == 0. {
+            0.
+        } else {
+            chroma / (1. - (2. * lightness - 1.).abs())
+        };
+
+        (hue(components, max, chroma), saturation, lightness)
+    }
+
+    /// Returns hue in degrees, saturation and value of the color in sRGB.
+    #[must_use]
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let components = self.convert(ColorSpace::Srgb).components();
+        let max = components.into_iter().fold(f32::MIN, f32::max);
+        let min = components.into_iter().fold(f32::MAX, f32::min);
+        let chroma = max - min;
+        let saturation = if max == 0. { 0. } else { chroma / max };
+
+        (hue(components, max, chroma), saturation, max)
+    }
+
+    fn to_oklab(self) -> [f32; 3] {
+        let lms = transform(
+            &LINEAR_SRGB_TO_LMS,
+            self.convert(ColorSpace::LinearSrgb).components(),
+        );
+
+        transform(&LMS_TO_OKLAB, lms.map(f32::cbrt))
+    }
+
+    /// Returns lightness, chroma and hue in degrees of the color.
+    #[must_use]
+    pub fn to_oklch(self) -> (f32, f32, f32) {
+        let [lightness, a, b] = self.to_oklab();
+
+        (
+            lightness,
+            a.hypot(b),
+            b.atan2(a).to_degrees().rem_euclid(360.),
+        )
+    }
+
+    /// Returns the color as 8-bit sRGB components in RGBA order, clamping
+    /// colors outside of the sRGB gamut.
+    #[must_use]
+    pub fn to_rgba8(self) -> [u8; 4] {
+        let color = self.convert(ColorSpace::Srgb).clamp();
+
+        [color.red, color.green, color.blue, color.alpha].map(|value| (value * 255.).round() as u8)
+    }
+
+    /// Formats the color as `#RRGGBB`, or `#RRGGBBAA` if it is translucent.
+    #[must_use]
+    pub fn to_hex(self) -> String {
+        let [red, green, blue, alpha] = self.to_rgba8();
+
+        if alpha == 0xFF {
+            format!("#{red:02X}{green:02X}{blue:02X}")
+        } else {
+            format!("#{red:02X}{green:02X}{blue:02X}{alpha:02X}")
+        }
+    }
+
+    /// Interpolates between the colors in Oklab, returning a color in the
+    /// space of `self`.
+    #[must_use]
+    pub fn lerp(self, other: Self, fraction: f32) -> Self {
+        let start = self.to_oklab();
+        let end = other.to_oklab();
+        let lab = [0, 1, 2].map(|index| start[index] + (end[index] - start[index]) * fraction);
+        let alpha = self.alpha + (other.alpha - self.alpha) * fraction;
+
+        Self::from_oklab(lab, alpha).convert(self.space)
+    }
+
+    /// Draws the color over `background`, returning the result in the space
+    /// of `background`.
+    #[must_use]
+    pub fn composite_over(self, background: Self) -> Self {
+        let foreground = self.convert(background.space);
+        let alpha = foreground.alpha + background.alpha * (1. - foreground.alpha);
+
+        if alpha == 0. {
+            return Self::TRANSPARENT.convert(background.space);
+        }
+
+        let [red, green, blue] = [0, 1, 2].map(|index| {
+            (foreground.components()[index] * foreground.alpha
+                + background.components()[index] * background.alpha * (1. - foreground.alpha))
+                / alpha
+        });
+
+        Self::new_in(background.space, red, green, blue, alpha)
+    }
+
+    /// Returns the relative luminance as defined by WCAG, from 0 for black to
+    /// 1 for white.
+    #[must_use]
+    pub fn luminance(self) -> f32 {
+        let [red, green, blue] = self.convert(ColorSpace::LinearSrgb).components();
+
+        (0.212_6 * red + 0.715_2 * green + 0.072_2 * blue).clamp(0., 1.)
+    }
+
+    /// Returns the WCAG contrast ratio between the colors, from 1 to 21.
+    #[must_use]
+    pub fn contrast_ratio(self, other: Self) -> f32 {
+        let first = self.luminance();
+        let second = other.luminance();
+
+        (first.max(second) + 0.05) / (first.min(second) + 0.05)
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Self::TRANSPARENT
+    }
+}
+
+/// Reads an ARGB value such as `0xFF336699`.
+impl From<u32> for Color {
+    fn from(value: u32) -> Self {
+        let [alpha, red, green, blue] = value.to_be_bytes();
+
+        Self::from_argb(alpha, red, green, blue)
+    }
+}
+
+impl From<Color> for u32 {
+    fn from(value: Color) -> Self {
+        let [red, green, blue, alpha] = value.to_rgba8();
+
+        Self::from_be_bytes([alpha, red, green, blue])
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError(String);
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid hex color `{}`", self.0)
+    }
+}
+
+impl Error for ParseColorError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: Color = Color::from_rgb(0x33, 0x66, 0x99);
+
+    fn assert_close(actual: (f32, f32, f32), expected: (f32, f32, f32), tolerance: f32) {
+        assert!(
+            (actual.0 - expected.0).abs() < tolerance
+                && (actual.1 - expected.1).abs() < tolerance
+                && (actual.2 - expected.2).abs() < tolerance,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn hsl() {
+        assert_close(SAMPLE.to_hsl(), (210., 0.5, 0.4), 1e-3);
+        assert_eq!(Color::from_hsl(210., 0.5, 0.4, 1.).to_rgba8(), [
+            0x33, 0x66, 0x99, 0xFF
+        ]);
+    }
+
+    #[test]
+    fn hsv() {
+        assert_close(SAMPLE.to_hsv(), (210., 2. / 3., 0.6), 1e-3);
+        assert_eq!(Color::from_hsv(210., 2. / 3., 0.6, 1.).to_rgba8(), [
+            0x33, 0x66, 0x99, 0xFF
+        ]);
+    }
+
+    #[test]
+    fn oklch() {
+        let (lightness, chroma, hue) = SAMPLE.to_oklch();
+
+        assert!((lightness - 0.499_3).abs() < 1e-3, "{lightness}");
+        assert!((chroma - 0.098_7).abs() < 1e-3, "{chroma}");
+        assert!((hue - 250.43).abs() < 0.1, "{hue}");
+        assert_eq!(Color::from_oklch(lightness, chroma, hue, 1.).to_rgba8(), [
+            0x33, 0x66, 0x99, 0xFF
+        ]);
+    }
+
+    #[test]
+    fn display_p3_round_trip() {
+        let color = Color::new_in(ColorSpace::DisplayP3, 0.2, 0.5, 0.8, 0.5);
+        let srgb = color.convert(ColorSpace::Srgb);
+        let back = srgb.convert(ColorSpace::DisplayP3);
+
+        assert_eq!(srgb.space, ColorSpace::Srgb);
+        assert_eq!(back.space, ColorSpace::DisplayP3);
+        assert_close(
+            (back.red, back.green, back.blue),
+            (color.red, color.green, color.blue),
+            1e-4,
+        );
+        assert!((back.alpha - color.alpha).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn from_hex() {
+        assert_eq!(Color::from_hex("#369"), Ok(SAMPLE));
+        assert_eq!(
+            Color::from_hex("3698"),
+            Ok(SAMPLE.with_alpha(f32::from(0x88_u8) / 255.))
+        );
+        assert_eq!(Color::from_hex("#336699"), Ok(SAMPLE));
+        assert_eq!(
+            Color::from_hex("#33669980"),
+            Ok(SAMPLE.with_alpha(f32::from(0x80_u8) / 255.))
+        );
+        assert_eq!(SAMPLE.to_hex(), "#336699");
+
+        for invalid in ["", "#", "#12", "#12345", "#1234567", "#ggg", "#éé", "##369"] {
+            assert!(Color::from_hex(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        assert!((Color::WHITE.contrast_ratio(Color::BLACK) - 21.).abs() < 1e-4);
+        assert!((Color::BLACK.contrast_ratio(Color::WHITE) - 21.).abs() < 1e-4);
+        assert!((SAMPLE.contrast_ratio(SAMPLE) - 1.).abs() < f32::EPSILON);
+    }
+}
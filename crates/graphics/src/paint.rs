@@ -0,0 +1,84 @@
+use std::rc::Rc;
+
+use crate::Color;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum PaintStyle {
+    #[default]
+    Fill,
+    Stroke {
+        width: f32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Paint {
+    pub color: Color,
+    pub style: PaintStyle,
+    pub anti_alias: bool,
+}
+
+impl Paint {
+    #[must_use]
+    pub const fn fill(color: Color) -> Self {
+        Self {
+            color,
+            style: PaintStyle::Fill,
+            anti_alias: true,
+        }
+    }
+
+    #[must_use]
+    pub const fn stroke(color: Color, width: f32) -> Self {
+        Self {
+            color,
+            style: PaintStyle::Stroke { width },
+            anti_alias: true,
+        }
+    }
+
+    #[must_use]
+    pub const fn anti_alias(mut self, value: bool) -> Self {
+        self.anti_alias = value;
+
+        self
+    }
+}
+
+/// Raster image with unpremultiplied RGBA pixels, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageData {
+    width: u32,
+    height: u32,
+    pixels: Rc<[u8]>,
+}
+
+impl ImageData {
+    /// Returns `None` if `pixels` does not hold exactly `width * height` RGBA
+    /// pixels.
+    #[must_use]
+    pub fn new(width: u32, height: u32, pixels: impl Into<Rc<[u8]>>) -> Option<Self> {
+        let pixels = pixels.into();
+
+        (pixels.len() == width as usize * height as usize * 4).then_some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    #[must_use]
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+}
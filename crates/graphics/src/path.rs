@@ -0,0 +1,153 @@
+/// Axis-aligned rectangle in canvas coordinates.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    #[must_use]
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[must_use]
+    pub const fn right(&self) -> f32 {
+        self.x + self.width
+    }
+
+    #[must_use]
+    pub const fn bottom(&self) -> f32 {
+        self.y + self.height
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathCommand {
+    MoveTo(f32, f32),
+    LineTo(f32, f32),
+    QuadTo(f32, f32, f32, f32),
+    CubicTo(f32, f32, f32, f32, f32, f32),
+    Close,
+}
+
+/// Backend independent path, replayed by each [`Canvas`](crate::Canvas)
+/// implementation into its own path type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Path(Vec<PathCommand>);
+
+impl Path {
+    /// Distance of cubic control points from a corner that approximates a
+    /// quarter circle.
+    const KAPPA: f32 = 0.552_284_8;
+
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    #[must_use]
+    pub fn rect(rect: Rect) -> Self {
+        Self::new()
+            .move_to(rect.x, rect.y)
+            .line_to(rect.right(), rect.y)
+            .line_to(rect.right(), rect.bottom())
+            .line_to(rect.x, rect.bottom())
+            .close()
+    }
+
+    #[must_use]
+    pub fn round_rect(rect: Rect, radius: f32) -> Self {
+        let radius = radius.min(rect.width / 2.).min(rect.height / 2.).max(0.);
+        let control = radius * (1. - Self::KAPPA);
+        let (left, top, right, bottom) = (rect.x, rect.y, rect.right(), rect.bottom());
+
+        Self::new()
+            .move_to(left + radius, top)
+            .line_to(right - radius, top)
+            .cubic_to(
+                right - control,
+                top,
+                right,
+                top + control,
+                right,
+                top + radius,
+            )
+            .line_to(right, bottom - radius)
+            .cubic_to(
+                right,
+                bottom - control,
+                right - control,
+                bottom,
+                right - radius,
+                bottom,
+            )
+            .line_to(left + radius, bottom)
+            .cubic_to(
+                left + control,
+                bottom,
+                left,
+                bottom - control,
+                left,
+                bottom - radius,
+            )
+            .line_to(left, top + radius)
+            .cubic_to(left, top + control, left + control, top, left + radius, top)
+            .close()
+    }
+
+    #[must_use]
+    pub fn circle(x: f32, y: f32, radius: f32) -> Self {
+        Self::round_rect(
+            Rect::new(x - radius, y - radius, radius * 2., radius * 2.),
+            radius,
+        )
+    }
+
+    #[must_use]
+    pub fn move_to(mut self, x: f32, y: f32) -> Self {
+        self.0.push(PathCommand::MoveTo(x, y));
+
+        self
+    }
+
+    #[must_use]
+    pub fn line_to(mut self, x: f32, y: f32) -> Self {
+        self.0.push(PathCommand::LineTo(x, y));
+
+        self
+    }
+
+    #[must_use]
+    pub fn quad_to(mut self, x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        self.0.push(PathCommand::QuadTo(x1, y1, x2, y2));
+
+        self
+    }
+
+    #[must_use]
+    pub fn cubic_to(mut self, x1: f32, y1: f32, x2: f32, y2: f32, x3: f32, y3: f32) -> Self {
+        self.0.push(PathCommand::CubicTo(x1, y1, x2, y2, x3, y3));
+
+        self
+    }
+
+    #[must_use]
+    pub fn close(mut self) -> Self {
+        self.0.push(PathCommand::Close);
+
+        self
+    }
+
+    #[must_use]
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.0
+    }
+}
@@ -0,0 +1,384 @@
+use std::rc::Rc;
+
+use tiny_skia::{
+    ColorU8, FillRule, FilterQuality, Mask, Pattern, Pixmap, PixmapPaint, SpreadMode, Stroke,
+    Transform,
+};
+use ttf_parser::{Face, OutlineBuilder};
+
+use crate::{Canvas, Color, ImageData, Paint, PaintStyle, Path, PathCommand, Rect, Renderer};
+
+fn to_color(color: Color) -> tiny_skia::Color {
+    let [red, green, blue, alpha] = color.to_rgba8();
+
+    tiny_skia::Color::from_rgba8(red, green, blue, alpha)
+}
+
+fn to_paint(paint: &Paint) -> tiny_skia::Paint<'static> {
+    let mut result = tiny_skia::Paint::default();
+
+    result.set_color(to_color(paint.color));
+    result.anti_alias = paint.anti_alias;
+
+    result
+}
+
+fn to_path(path: &Path) -> Option<tiny_skia::Path> {
+    let mut builder = tiny_skia::PathBuilder::new();
+
+    for command in path.commands() {
+        match *command {
+            PathCommand::MoveTo(x, y) => builder.move_to(x, y),
+            PathCommand::LineTo(x, y) => builder.line_to(x, y),
+            PathCommand::QuadTo(x1, y1, x2, y2) => builder.quad_to(x1, y1, x2, y2),
+            PathCommand::CubicTo(x1, y1, x2, y2, x3, y3) => {
+                builder.cubic_to(x1, y1, x2, y2, x3, y3);
+            }
+            PathCommand::Close => builder.close(),
+        }
+    }
+
+    builder.finish()
+}
+
+fn to_pixmap(image: &ImageData) -> Option<Pixmap> {
+    let mut pixmap = Pixmap::new(image.width(), image.height())?;
+
+    for (pixel, rgba) in pixmap
+        .pixels_mut()
+        .iter_mut()
+        .zip(image.pixels().chunks_exact(4))
+    {
+        *pixel = ColorU8::from_rgba(rgba[0], rgba[1], rgba[2], rgba[3]).premultiply();
+    }
+
+    Some(pixmap)
+}
+
+/// Collects glyph outlines into a path, flipping them from font units into
+/// a y-down coordinate space.
+struct GlyphOutline {
+    builder: tiny_skia::PathBuilder,
+    offset: f32,
+}
+
+impl OutlineBuilder for GlyphOutline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.builder.move_to(self.offset + x, -y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.builder.line_to(self.offset + x, -y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.builder
+            .quad_to(self.offset + x1, -y1, self.offset + x, -y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.builder.cubic_to(
+            self.offset + x1,
+            -y1,
+            self.offset + x2,
+            -y2,
+            self.offset + x,
+            -y,
+        );
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+}
+
+struct Layer {
+    pixmap: Pixmap,
+    alpha: f32,
+    depth: usize,
+}
+
+/// Pure Rust backend drawing into a [`Pixmap`]. Text is drawn from glyph
+/// outlines of the font set with [`TinySkiaRenderer::with_font`] and skipped
+/// without one.
+pub struct TinySkiaRenderer {
+    pixmap: Pixmap,
+    font: Option<Rc<[u8]>>,
+}
+
+impl TinySkiaRenderer {
+    /// # Panics
+    ///
+    /// Panics if either dimension is zero.
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            pixmap: Pixmap::new(width, height).expect("invalid renderer size"),
+            font: None,
+        }
+    }
+
+    /// Sets the TrueType or OpenType font used for text.
+    #[must_use]
+    pub fn with_font(mut self, data: impl Into<Rc<[u8]>>) -> Self {
+        self.font = Some(data.into());
+
+        self
+    }
+
+    #[must_use]
+    pub const fn pixmap(&self) -> &Pixmap {
+        &self.pixmap
+    }
+}
+
+impl Renderer for TinySkiaRenderer {
+    fn size(&self) -> (u32, u32) {
+        (self.pixmap.width(), self.pixmap.height())
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        self.pixmap = Pixmap::new(width, height).expect("invalid renderer size");
+    }
+
+    fn with_canvas(&mut self, draw: &mut dyn FnMut(&mut dyn Canvas)) {
+        let face = self
+            .font
+            .as_deref()
+            .and_then(|data| Face::parse(data, 0).ok());
+
+        draw(&mut TinySkiaCanvas {
+            base: &mut self.pixmap,
+            face,
+            transform: Transform::identity(),
+            mask: None,
+            stack: Vec::new(),
+            layers: Vec::new(),
+        });
+    }
+
+    fn read_rgba(&mut self) -> Vec<u8> {
+        self.pixmap
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect()
+    }
+}
+
+pub struct TinySkiaCanvas<'a> {
+    base: &'a mut Pixmap,
+    face: Option<Face<'a>>,
+    transform: Transform,
+    mask: Option<Mask>,
+    stack: Vec<(Transform, Option<Mask>)>,
+    layers: Vec<Layer>,
+}
+
+impl TinySkiaCanvas<'_> {
+    fn target(&mut self) -> (&mut Pixmap, Transform, Option<&Mask>) {
+        let target = match self.layers.last_mut() {
+            Some(layer) => &mut layer.pixmap,
+            None => &mut *self.base,
+        };
+
+        (target, self.transform, self.mask.as_ref())
+    }
+
+    fn fill_or_stroke(&mut self, path: &tiny_skia::Path, paint: &Paint) {
+        let skia_paint = to_paint(paint);
+        let (target, transform, mask) = self.target();
+
+        match paint.style {
+            PaintStyle::Fill => {
+                target.fill_path(path, &skia_paint, FillRule::Winding, transform, mask);
+            }
+            PaintStyle::Stroke { width } => {
+                let stroke = Stroke {
+                    width,
+                    ..Stroke::default()
+                };
+
+                target.stroke_path(path, &skia_paint, &stroke, transform, mask);
+            }
+        }
+    }
+}
+
+impl Canvas for TinySkiaCanvas<'_> {
+    fn save(&mut self) {
+        self.stack.push((self.transform, self.mask.clone()));
+    }
+
+    fn save_layer(&mut self, alpha: f32) {
+        self.save();
+
+        if let Some(pixmap) = Pixmap::new(self.base.width(), self.base.height()) {
+            self.layers.push(Layer {
+                pixmap,
+                alpha,
+                depth: self.stack.len(),
+            });
+        }
+    }
+
+    fn restore(&mut self) {
+        if self
+            .layers
+            .last()
+            .is_some_and(|layer| layer.depth == self.stack.len())
+        {
+            let layer = self.layers.pop().unwrap();
+            let paint = PixmapPaint {
+                opacity: layer.alpha,
+                ..PixmapPaint::default()
+            };
+
+            // Layers are composited in device space, under the clip of the state
+            // that was active when the layer was created.
+            let mask = self.stack.last().and_then(|(_, mask)| mask.as_ref());
+            let target = match self.layers.last_mut() {
+                Some(layer) => &mut layer.pixmap,
+                None => &mut *self.base,
+            };
+
+            target.draw_pixmap(
+                0,
+                0,
+                layer.pixmap.as_ref(),
+                &paint,
+                Transform::identity(),
+                mask,
+            );
+        }
+
+        if let Some((transform, mask)) = self.stack.pop() {
+            self.transform = transform;
+            self.mask = mask;
+        }
+    }
+
+    fn translate(&mut self, dx: f32, dy: f32) {
+        self.transform = self.transform.pre_translate(dx, dy);
+    }
+
+    fn scale(&mut self, sx: f32, sy: f32) {
+        self.transform = self.transform.pre_scale(sx, sy);
+    }
+
+    fn rotate(&mut self, degrees: f32) {
+        self.transform = self.transform.pre_concat(Transform::from_rotate(degrees));
+    }
+
+    fn clip_rect(&mut self, rect: Rect) {
+        self.clip_path(&Path::rect(rect));
+    }
+
+    fn clip_path(&mut self, path: &Path) {
+        let Some(path) = to_path(path) else {
+            return;
+        };
+
+        match &mut self.mask {
+            Some(mask) => mask.intersect_path(&path, FillRule::Winding, true, self.transform),
+            None => {
+                if let Some(mut mask) = Mask::new(self.base.width(), self.base.height()) {
+                    mask.fill_path(&path, FillRule::Winding, true, self.transform);
+
+                    self.mask = Some(mask);
+                }
+            }
+        }
+    }
+
+    fn clear(&mut self, color: Color) {
+        self.target().0.fill(to_color(color));
+    }
+
+    fn draw_rect(&mut self, rect: Rect, paint: &Paint) {
+        if let Some(path) = to_path(&Path::rect(rect)) {
+            self.fill_or_stroke(&path, paint);
+        }
+    }
+
+    fn draw_round_rect(&mut self, rect: Rect, radius: f32, paint: &Paint) {
+        if let Some(path) = to_path(&Path::round_rect(rect, radius)) {
+            self.fill_or_stroke(&path, paint);
+        }
+    }
+
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, paint: &Paint) {
+        if let Some(path) = tiny_skia::PathBuilder::from_circle(x, y, radius) {
+            self.fill_or_stroke(&path, paint);
+        }
+    }
+
+    fn draw_path(&mut self, path: &Path, paint: &Paint) {
+        if let Some(path) = to_path(path) {
+            self.fill_or_stroke(&path, paint);
+        }
+    }
+
+    fn draw_image(&mut self, image: &ImageData, dst: Rect) {
+        let (Some(pixmap), Some(rect)) = (
+            to_pixmap(image),
+            tiny_skia::Rect::from_xywh(dst.x, dst.y, dst.width, dst.height),
+        ) else {
+            return;
+        };
+
+        let paint = tiny_skia::Paint {
+            shader: Pattern::new(
+                pixmap.as_ref(),
+                SpreadMode::Pad,
+                FilterQuality::Bilinear,
+                1.,
+                Transform::from_row(
+                    dst.width / image.width() as f32,
+                    0.,
+                    0.,
+                    dst.height / image.height() as f32,
+                    dst.x,
+                    dst.y,
+                ),
+            ),
+            ..tiny_skia::Paint::default()
+        };
+
+        let (target, transform, mask) = self.target();
+
+        target.fill_rect(rect, &paint, transform, mask);
+    }
+
+    fn draw_text(&mut self, text: &str, x: f32, y: f32, font_size: f32, paint: &Paint) {
+        let Some(face) = &self.face else {
+            return;
+        };
+
+        let scale = font_size / f32::from(face.units_per_em());
+        let mut outline = GlyphOutline {
+            builder: tiny_skia::PathBuilder::new(),
+            offset: 0.,
+        };
+
+        for glyph in text.chars().filter_map(|char| face.glyph_index(char)) {
+            face.outline_glyph(glyph, &mut outline);
+
+            outline.offset += f32::from(face.glyph_hor_advance(glyph).unwrap_or_default());
+        }
+
+        let Some(path) = outline
+            .builder
+            .finish()
+            .and_then(|path| path.transform(Transform::from_row(scale, 0., 0., scale, x, y)))
+        else {
+            return;
+        };
+
+        self.fill_or_stroke(&path, paint);
+    }
+}
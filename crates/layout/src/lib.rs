@@ -1,5 +1,9 @@
 use rumpose_geometry::{Point, Rect, Size};
 
+mod unit;
+
+pub use self::unit::{Density, Dp, Sp, UnitExt};
+
 pub type Point2D = Point<f32>;
 pub type Size2D = Size<f32>;
 pub type Rect2D = Rect<f32>;
@@ -71,3 +75,46 @@ impl Default for Constraints {
         Self::new(0., f32::INFINITY, 0., f32::INFINITY)
     }
 }
+
+/// Position of content inside a larger space, where `-1` is the start edge and
+/// `1` is the end edge of the axis.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Alignment {
+    pub horizontal: f32,
+    pub vertical: f32,
+}
+
+impl Alignment {
+    pub const BOTTOM_CENTER: Self = Self::new(0., 1.);
+    pub const BOTTOM_END: Self = Self::new(1., 1.);
+    pub const BOTTOM_START: Self = Self::new(-1., 1.);
+    pub const CENTER: Self = Self::new(0., 0.);
+    pub const CENTER_END: Self = Self::new(1., 0.);
+    pub const CENTER_START: Self = Self::new(-1., 0.);
+    pub const TOP_CENTER: Self = Self::new(0., -1.);
+    pub const TOP_END: Self = Self::new(1., -1.);
+    pub const TOP_START: Self = Self::new(-1., -1.);
+
+    #[must_use]
+    pub const fn new(horizontal: f32, vertical: f32) -> Self {
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
+
+    /// Returns the offset of content with the given `size` inside `space`.
+    #[must_use]
+    pub fn align(&self, size: Size2D, space: Size2D) -> Point2D {
+        Point2D::new(
+            (space.width - size.width) / 2. * (1. + self.horizontal),
+            (space.height - size.height) / 2. * (1. + self.vertical),
+        )
+    }
+}
+
+impl Default for Alignment {
+    fn default() -> Self {
+        Self::CENTER
+    }
+}
@@ -0,0 +1,136 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Scale of the output relative to a baseline of one pixel per [`Dp`], and the
+/// user's preferred text scale applied on top of it for [`Sp`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Density {
+    pub density: f32,
+    pub font_scale: f32,
+}
+
+impl Density {
+    #[must_use]
+    pub const fn new(density: f32, font_scale: f32) -> Self {
+        Self {
+            density,
+            font_scale,
+        }
+    }
+
+    #[must_use]
+    pub const fn px_to_dp(&self, value: f32) -> Dp {
+        Dp(value / self.density)
+    }
+
+    #[must_use]
+    pub const fn px_to_sp(&self, value: f32) -> Sp {
+        Sp(value / (self.density * self.font_scale))
+    }
+}
+
+impl Default for Density {
+    fn default() -> Self {
+        Self::new(1., 1.)
+    }
+}
+
+/// Density-independent length, converted to pixels at measure and draw time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Dp(pub f32);
+
+impl Dp {
+    #[must_use]
+    pub const fn to_px(self, density: Density) -> f32 {
+        self.0 * density.density
+    }
+}
+
+/// Scale-independent length for text, which also follows
+/// [`Density::font_scale`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Sp(pub f32);
+
+impl Sp {
+    #[must_use]
+    pub const fn to_px(self, density: Density) -> f32 {
+        self.0 * density.density * density.font_scale
+    }
+}
+
+macro_rules! impl_unit {
+    ($unit:ident) => {
+        impl From<f32> for $unit {
+            fn from(value: f32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl Add for $unit {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $unit {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl Mul<f32> for $unit {
+            type Output = Self;
+
+            fn mul(self, rhs: f32) -> Self::Output {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl Div<f32> for $unit {
+            type Output = Self;
+
+            fn div(self, rhs: f32) -> Self::Output {
+                Self(self.0 / rhs)
+            }
+        }
+
+        impl Neg for $unit {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self(-self.0)
+            }
+        }
+    };
+}
+
+impl_unit!(Dp);
+impl_unit!(Sp);
+
+pub trait UnitExt {
+    fn dp(self) -> Dp;
+    fn sp(self) -> Sp;
+}
+
+impl UnitExt for f32 {
+    fn dp(self) -> Dp {
+        Dp(self)
+    }
+
+    fn sp(self) -> Sp {
+        Sp(self)
+    }
+}
+
+impl UnitExt for i32 {
+    fn dp(self) -> Dp {
+        Dp(self as f32)
+    }
+
+    fn sp(self) -> Sp {
+        Sp(self as f32)
+    }
+}
@@ -35,6 +35,12 @@ fn main() {
     let iterations = 10u8;
 
     for _ in 0..iterations {
+        recomposer.with_composer(|composer| {
+            composer.dispatch_pointer_event(PointerEvent::scroll(
+                Point2D::new(128., 512.),
+                Point2D::new(0., 100.),
+            ))
+        });
         recomposer.recompose();
 
         recomposer.with_composer_mut(|composer| {
@@ -56,7 +62,9 @@ fn main() {
 }
 
 fn app(scope: Scope) {
-    lazy_column(scope, 1000, move |scope, index| {
+    let state = ScrollState::new(scope);
+
+    lazy_column(scope, Modifier, state, 1000, move |scope, index| {
         let theme = scope.use_state(|| {
             ThemeBuilder::with_source(Argb::from_u32(0x4FF156))
                 .build()
@@ -75,56 +83,5 @@ fn app(scope: Scope) {
                 )
             },
         );
-    })
-}
-
-fn lazy_column(
-    scope: Scope,
-    items_count: usize,
-    item_builder: impl Fn(Scope, usize) + Clone + 'static,
-) {
-    subcompose_layout(
-        scope,
-        move |_, layout_context, root_constraints, compose_context| {
-            let mut height = 0.;
-            let mut width = root_constraints.min.width;
-            let mut composed_items = 0u16;
-
-            for item in 0..items_count {
-                let item_builder = item_builder.clone();
-
-                let areas = compose_context.compose(
-                    item,
-                    move |scope| item_builder(scope, item),
-                    layout_context,
-                    root_constraints,
-                    |layout_context, id, node| {
-                        (id, node.measure(layout_context, Constraints::default()))
-                    },
-                );
-
-                for (id, area) in areas {
-                    compose_context.place_relative(item, id, 0.0, height);
-
-                    width = width.max(area.width);
-                    height += area.height;
-                }
-
-                composed_items += 1;
-
-                if height >= root_constraints.max.height {
-                    println!("Composed {composed_items} items");
-
-                    break;
-                }
-            }
-
-            println!(
-                "Column height: composed = {height}px, real = {}px\n",
-                (height / (f32::from(composed_items))) * (items_count as f32)
-            );
-
-            root_constraints.apply(Size2D::new(width, height))
-        },
-    );
+    });
 }